@@ -0,0 +1,82 @@
+use crate::args::{self, Arg, Args};
+use crate::timestamp;
+use folktime::{
+    duration::{DurationStyle, Style},
+    Folktime,
+};
+use std::process::ExitCode;
+use std::time::SystemTime;
+
+/// How long before `now` the file at `path` was last modified.
+pub fn age(path: &str, now: SystemTime, style: &dyn DurationStyle) -> Result<String, String> {
+    let modified = std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .map_err(|e| format!("{path}: {e}"))?;
+    Ok(Folktime::relative(modified, now)
+        .with_style(style)
+        .to_string())
+}
+
+pub fn run(args: impl Iterator<Item = String>) -> Result<ExitCode, String> {
+    let mut now = None;
+    let mut style: Box<dyn DurationStyle> = Box::new(Style::OneUnitFrac);
+    let mut paths = vec![];
+    let mut args = Args::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Option(o) => match o.as_str() {
+                "-n" | "--now" => {
+                    let value = args.value(&o)?;
+                    let time = timestamp::parse(&value)
+                        .ok_or_else(|| format!("invalid timestamp `{value}`"))?;
+                    now = Some(time);
+                }
+                "-s" | "--style" => style = args::style(&args.value(&o)?)?,
+                "-h" | "--help" => {
+                    print!("{}", crate::USAGE);
+                    return Ok(ExitCode::SUCCESS);
+                }
+                _ => return Err(format!("unknown option `{o}`")),
+            },
+            Arg::Value(v) => paths.push(v),
+        }
+    }
+    if paths.is_empty() {
+        return Err("missing paths".to_string());
+    }
+    let now = now.unwrap_or_else(SystemTime::now);
+    let mut code = ExitCode::SUCCESS;
+    for path in &paths {
+        match age(path, now, &style) {
+            Ok(age) => println!("{age}\t{path}"),
+            Err(e) => {
+                eprintln!("folktime: {e}");
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+    Ok(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn modified() {
+        let path = std::env::temp_dir().join(format!("folktime-age-{}", std::process::id()));
+        let file = std::fs::File::create(&path).unwrap();
+        let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_714_566_600);
+        file.set_modified(modified).unwrap();
+        let now = modified + Duration::from_secs(192);
+        let age = age(path.to_str().unwrap(), now, &Style::OneUnitFrac);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(age.unwrap(), "3.20m ago");
+    }
+    #[test]
+    fn missing() {
+        let now = SystemTime::now();
+        assert!(age("/nonexistent/folktime", now, &Style::OneUnitFrac).is_err());
+    }
+}
@@ -0,0 +1,151 @@
+use crate::args::{self, Arg, Args};
+use crate::timestamp;
+use folktime::{
+    duration::{DurationStyle, Style},
+    Folktime,
+};
+use std::io::{BufRead, Write};
+use std::process::ExitCode;
+use std::time::SystemTime;
+
+/// What to do with the timestamps found in each line.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Replace every timestamp with its time relative to the reference time.
+    Ago,
+    /// Append the time since the previous line's timestamp after the first one.
+    Delta,
+}
+
+pub struct Annotator {
+    mode: Mode,
+    now: SystemTime,
+    style: Box<dyn DurationStyle>,
+    previous: Option<SystemTime>,
+}
+
+impl Annotator {
+    pub fn new(mode: Mode, now: SystemTime, style: Box<dyn DurationStyle>) -> Self {
+        Self {
+            mode,
+            now,
+            style,
+            previous: None,
+        }
+    }
+
+    /// `line` with its timestamps rewritten or annotated.
+    pub fn line(&mut self, line: &str) -> String {
+        let found = timestamp::find(line);
+        match self.mode {
+            Mode::Ago => {
+                let mut out = String::with_capacity(line.len());
+                let mut end = 0;
+                for (range, time) in found {
+                    out += &line[end..range.start];
+                    let relative = Folktime::relative(time, self.now).with_style(&self.style);
+                    out += &relative.to_string();
+                    end = range.end;
+                }
+                out + &line[end..]
+            }
+            Mode::Delta => {
+                let Some((range, time)) = found.into_iter().next() else {
+                    return line.to_string();
+                };
+                let previous = self.previous.replace(time).unwrap_or(time);
+                let delta = match time.duration_since(previous) {
+                    Ok(d) => format!("+{}", Folktime::duration(d).with_style(&self.style)),
+                    Err(e) => {
+                        let d = e.duration();
+                        format!("-{}", Folktime::duration(d).with_style(&self.style))
+                    }
+                };
+                format!("{} {delta}{}", &line[..range.end], &line[range.end..])
+            }
+        }
+    }
+}
+
+pub fn run(args: impl Iterator<Item = String>) -> Result<ExitCode, String> {
+    let mut mode = Mode::Ago;
+    let mut now = None;
+    let mut style: Box<dyn DurationStyle> = Box::new(Style::OneUnitFrac);
+    let mut files = vec![];
+    let mut args = Args::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Option(o) => match o.as_str() {
+                "-d" | "--delta" => mode = Mode::Delta,
+                "-a" | "--ago" => mode = Mode::Ago,
+                "-n" | "--now" => {
+                    let value = args.value(&o)?;
+                    let time = timestamp::parse(&value)
+                        .ok_or_else(|| format!("invalid timestamp `{value}`"))?;
+                    now = Some(time);
+                }
+                "-s" | "--style" => style = args::style(&args.value(&o)?)?,
+                "-h" | "--help" => {
+                    print!("{}", crate::USAGE);
+                    return Ok(ExitCode::SUCCESS);
+                }
+                _ => return Err(format!("unknown option `{o}`")),
+            },
+            Arg::Value(v) => files.push(v),
+        }
+    }
+    let mut annotator = Annotator::new(mode, now.unwrap_or_else(SystemTime::now), style);
+    let mut out = std::io::stdout().lock();
+    let mut annotate = |input: &mut dyn BufRead| -> std::io::Result<()> {
+        for line in input.lines() {
+            writeln!(out, "{}", annotator.line(&line?))?;
+        }
+        Ok(())
+    };
+    let result = if files.is_empty() {
+        annotate(&mut std::io::stdin().lock())
+    } else {
+        files.iter().try_for_each(|path| {
+            let file = std::fs::File::open(path)?;
+            annotate(&mut std::io::BufReader::new(file))
+        })
+    };
+    match result {
+        Ok(()) => Ok(ExitCode::SUCCESS),
+        Err(e) if e.kind() == std::io::ErrorKind::BrokenPipe => Ok(ExitCode::SUCCESS),
+        Err(e) => {
+            eprintln!("folktime: {e}");
+            Ok(ExitCode::FAILURE)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn annotator(mode: Mode) -> Annotator {
+        let now = UNIX_EPOCH + Duration::from_secs(1_714_566_600);
+        Annotator::new(mode, now, Box::new(Style::OneUnitFrac))
+    }
+
+    #[test]
+    fn ago() {
+        let mut a = annotator(Mode::Ago);
+        assert_eq!(
+            a.line("1714566408 started, done at 2024-05-01T12:30:05Z"),
+            "3.20m ago started, done at in 5.00s"
+        );
+        assert_eq!(a.line("[@1714566540000] ok"), "[1.00m ago] ok");
+        assert_eq!(a.line("no timestamps 42"), "no timestamps 42");
+    }
+    #[test]
+    fn delta() {
+        let mut a = annotator(Mode::Delta);
+        assert_eq!(a.line("1714566600000 a"), "1714566600000 +0.00s a");
+        assert_eq!(a.line("1714566600012 b"), "1714566600012 +12.0ms b");
+        assert_eq!(a.line("plain"), "plain");
+        assert_eq!(a.line("[@1714566600002] c"), "[@1714566600002 -10.0ms] c");
+    }
+}
@@ -0,0 +1,94 @@
+use folktime::duration::{DurationStyle, Fraction, Fuzzy, Phrase, Style, Unit};
+
+/// A command-line argument, either an option or a positional value.
+pub enum Arg {
+    /// An option such as `-u` or `--unit`.
+    Option(String),
+    Value(String),
+}
+
+/// Splits command-line arguments into options and values.
+///
+/// Everything after `--` is a value.
+pub struct Args<I> {
+    args: I,
+    inline: Option<String>,
+    only_values: bool,
+}
+
+impl<I: Iterator<Item = String>> Args<I> {
+    pub fn new(args: I) -> Self {
+        Self {
+            args,
+            inline: None,
+            only_values: false,
+        }
+    }
+
+    /// The value of the option returned last, e.g. `ms` in `--unit ms` or `--unit=ms`.
+    pub fn value(&mut self, option: &str) -> Result<String, String> {
+        self.inline
+            .take()
+            .or_else(|| self.args.next())
+            .ok_or_else(|| format!("missing value for `{option}`"))
+    }
+
+    /// All remaining arguments, as values.
+    pub fn rest(&mut self) -> Vec<String> {
+        self.args.by_ref().collect()
+    }
+}
+
+impl<I: Iterator<Item = String>> Iterator for Args<I> {
+    type Item = Arg;
+
+    fn next(&mut self) -> Option<Arg> {
+        let arg = self.args.next()?;
+        if self.only_values || arg == "-" || !arg.starts_with('-') {
+            return Some(Arg::Value(arg));
+        }
+        if arg == "--" {
+            self.only_values = true;
+            return self.next();
+        }
+        match arg.split_once('=') {
+            Some((option, value)) if option.starts_with("--") => {
+                self.inline = Some(value.to_string());
+                Some(Arg::Option(option.to_string()))
+            }
+            _ => {
+                self.inline = None;
+                Some(Arg::Option(arg))
+            }
+        }
+    }
+}
+
+pub const STYLES: &str =
+    "one-unit-frac, one-unit-whole, two-units-whole, phrase, fuzzy, fraction, vulgar";
+
+/// A built-in [Style] or one of the other styles of the library, by name.
+pub fn style(name: &str) -> Result<Box<dyn DurationStyle>, String> {
+    match name {
+        "phrase" => Ok(Box::new(Phrase)),
+        "fuzzy" => Ok(Box::new(Fuzzy::new())),
+        "fraction" => Ok(Box::new(Fraction::words())),
+        "vulgar" => Ok(Box::new(Fraction::vulgar())),
+        _ => name
+            .parse::<Style>()
+            .map(|style| Box::new(style) as Box<dyn DurationStyle>)
+            .map_err(|e| format!("{e}, expected one of: {STYLES}")),
+    }
+}
+
+/// A unit of raw numbers: `ns`, `us`, `ms` or `s`.
+pub fn number_unit(name: &str) -> Result<Unit, String> {
+    match name.parse() {
+        Ok(unit @ (Unit::Nanosecond | Unit::Microsecond | Unit::Millisecond | Unit::Second)) => {
+            Ok(unit)
+        }
+        _ => Err(format!(
+            "unknown unit `{name}`, expected one of: ns, us, ms, s"
+        )),
+    }
+}
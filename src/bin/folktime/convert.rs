@@ -0,0 +1,59 @@
+use crate::args::{self, Arg, Args};
+use folktime::{
+    duration::{DurationStyle, Style, Unit},
+    Folktime,
+};
+use std::process::ExitCode;
+
+/// Parse a plain decimal number of `unit`s.
+pub fn number(input: &str, unit: Unit) -> Result<std::time::Duration, String> {
+    if input.is_empty() || !input.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return Err(format!("invalid number `{input}`"));
+    }
+    folktime::duration::parse(&format!("{input}{}", unit.symbol())).map_err(|e| e.to_string())
+}
+
+pub fn run(args: impl Iterator<Item = String>) -> Result<ExitCode, String> {
+    let mut unit = Unit::Second;
+    let mut style: Box<dyn DurationStyle> = Box::new(Style::OneUnitFrac);
+    let mut inputs = vec![];
+    let mut args = Args::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Option(o) => match o.as_str() {
+                "-u" | "--unit" => unit = args::number_unit(&args.value(&o)?)?,
+                "-s" | "--style" => style = args::style(&args.value(&o)?)?,
+                "-h" | "--help" => {
+                    print!("{}", crate::USAGE);
+                    return Ok(ExitCode::SUCCESS);
+                }
+                _ => return Err(format!("unknown option `{o}`")),
+            },
+            Arg::Value(v) => inputs.push(v),
+        }
+    }
+    Ok(crate::for_each_input(inputs, |input| {
+        let d = number(input, unit)?;
+        println!("{}", Folktime::duration(d).with_style(&style));
+        Ok(())
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(number("5", Unit::Second), Ok(Duration::from_secs(5)));
+        assert_eq!(
+            number("1.5", Unit::Millisecond),
+            Ok(Duration::from_micros(1500))
+        );
+        assert_eq!(number("12", Unit::Nanosecond), Ok(Duration::from_nanos(12)));
+        assert!(number("", Unit::Second).is_err());
+        assert!(number("-1", Unit::Second).is_err());
+        assert!(number("5m", Unit::Second).is_err());
+    }
+}
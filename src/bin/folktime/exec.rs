@@ -0,0 +1,321 @@
+use crate::args::{self, Arg, Args};
+use crate::json;
+use folktime::{
+    duration::{DurationStyle, Style},
+    Folktime,
+};
+use std::process::{Command, ExitCode, ExitStatus};
+use std::time::{Duration, Instant};
+
+/// Wall, user and system time of a finished command.
+pub struct Report {
+    pub status: ExitStatus,
+    pub wall: Duration,
+    /// User and system time, where the platform reports them.
+    pub cpu: Option<(Duration, Duration)>,
+}
+
+// The struct layout and constants below are those of the generic Linux ABI. Other
+// architectures, such as powerpc, mips and sparc, fall back to `Child::wait`.
+#[cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64"
+    )
+))]
+mod rusage {
+    use std::ffi::{c_int, c_long};
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::{Child, ExitStatus};
+    use std::time::Duration;
+
+    #[repr(C)]
+    struct Timeval {
+        tv_sec: c_long,
+        tv_usec: c_long,
+    }
+
+    #[repr(C)]
+    struct Rusage {
+        ru_utime: Timeval,
+        ru_stime: Timeval,
+        ru_other: [c_long; 14],
+    }
+
+    const SIGINT: c_int = 2;
+    const SIG_IGN: usize = 1;
+    const SIG_ERR: usize = usize::MAX;
+
+    extern "C" {
+        fn wait4(pid: c_int, status: *mut c_int, options: c_int, usage: *mut Rusage) -> c_int;
+        fn signal(signum: c_int, handler: usize) -> usize;
+    }
+
+    extern "C" fn ignore(_: c_int) {}
+
+    /// Ctrl-C reaches the child, which decides whether to stop, so it is ignored here until
+    /// the child is done, like `time` does. The previous handler is restored on drop.
+    ///
+    /// Install it before spawning the child. A handler, unlike `SIG_IGN`, is reset when the
+    /// child starts, so the child still gets Ctrl-C.
+    pub struct Interrupts(usize);
+
+    impl Interrupts {
+        pub fn ignore() -> std::io::Result<Self> {
+            // SAFETY: the handler does nothing, and restoring the previous one in `drop` has
+            // no other effect.
+            let previous = unsafe { signal(SIGINT, ignore as extern "C" fn(c_int) as usize) };
+            if previous == SIG_ERR {
+                return Err(std::io::Error::last_os_error());
+            }
+            if previous == SIG_IGN {
+                // Already ignored, e.g. in a background job, so the child ignores it too.
+                // SAFETY: as above.
+                unsafe { signal(SIGINT, SIG_IGN) };
+            }
+            Ok(Self(previous))
+        }
+
+        /// Whether Ctrl-C was already ignored, in which case the child ignores it too.
+        #[cfg(test)]
+        pub fn were_ignored(&self) -> bool {
+            self.0 == SIG_IGN
+        }
+    }
+
+    impl Drop for Interrupts {
+        fn drop(&mut self) {
+            // SAFETY: restores the handler that was replaced in `ignore`.
+            unsafe { signal(SIGINT, self.0) };
+        }
+    }
+
+    fn duration(t: &Timeval) -> Duration {
+        Duration::new(t.tv_sec as u64, t.tv_usec as u32 * 1_000)
+    }
+
+    /// Wait for `child` and get its user and system time.
+    pub fn wait(child: Child) -> std::io::Result<(ExitStatus, Option<(Duration, Duration)>)> {
+        let mut status = 0;
+        let mut usage = std::mem::MaybeUninit::<Rusage>::uninit();
+        loop {
+            // SAFETY: `wait4` fills in the struct when it succeeds, and `child` is ours and
+            // not waited for yet.
+            let pid = unsafe { wait4(child.id() as c_int, &mut status, 0, usage.as_mut_ptr()) };
+            if pid >= 0 {
+                break;
+            }
+            let e = std::io::Error::last_os_error();
+            if e.kind() != std::io::ErrorKind::Interrupted {
+                return Err(e);
+            }
+        }
+        // SAFETY: `wait4` succeeded.
+        let usage = unsafe { usage.assume_init() };
+        let cpu = (duration(&usage.ru_utime), duration(&usage.ru_stime));
+        Ok((ExitStatus::from_raw(status), Some(cpu)))
+    }
+}
+
+#[cfg(not(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64"
+    )
+)))]
+mod rusage {
+    use std::process::{Child, ExitStatus};
+
+    pub struct Interrupts;
+
+    impl Interrupts {
+        pub fn ignore() -> std::io::Result<Self> {
+            Ok(Self)
+        }
+    }
+
+    pub fn wait(
+        mut child: Child,
+    ) -> std::io::Result<(
+        ExitStatus,
+        Option<(std::time::Duration, std::time::Duration)>,
+    )> {
+        Ok((child.wait()?, None))
+    }
+}
+
+/// Run `command` to completion and measure it.
+pub fn time(command: &[String]) -> std::io::Result<Report> {
+    let _interrupts = rusage::Interrupts::ignore()?;
+    let start = Instant::now();
+    let child = Command::new(&command[0]).args(&command[1..]).spawn()?;
+    let (status, cpu) = rusage::wait(child)?;
+    let wall = start.elapsed();
+    Ok(Report { status, wall, cpu })
+}
+
+/// Exit code that passes `status` through, using `128 + signal` for killed commands.
+fn exit_code(status: ExitStatus) -> u8 {
+    #[cfg(unix)]
+    if let Some(signal) = std::os::unix::process::ExitStatusExt::signal(&status) {
+        return (128 + signal) as u8;
+    }
+    status.code().unwrap_or(1) as u8
+}
+
+fn text(report: &Report, style: &dyn DurationStyle) -> String {
+    let fmt = |d: Duration| Folktime::duration(d).with_style(style).to_string();
+    let mut out = format!("real {}", fmt(report.wall));
+    if let Some((user, sys)) = report.cpu {
+        out += &format!("\nuser {}\nsys  {}", fmt(user), fmt(sys));
+    }
+    out
+}
+
+fn json(command: &[String], report: &Report, style: &dyn DurationStyle) -> String {
+    let field = |name: &str, d: Duration| {
+        let folk = Folktime::duration(d).with_style(style).to_string();
+        format!(
+            r#""{name}":{{"ns":{},"folk":{}}}"#,
+            d.as_nanos(),
+            json::string(&folk)
+        )
+    };
+    let command: Vec<_> = command.iter().map(|arg| json::string(arg)).collect();
+    let mut fields = vec![
+        format!(r#""command":[{}]"#, command.join(",")),
+        format!(r#""status":{}"#, exit_code(report.status)),
+        field("real", report.wall),
+    ];
+    if let Some((user, sys)) = report.cpu {
+        fields.push(field("user", user));
+        fields.push(field("sys", sys));
+    }
+    format!("{{{}}}", fields.join(","))
+}
+
+pub fn run(args: impl Iterator<Item = String>) -> Result<ExitCode, String> {
+    let mut style: Box<dyn DurationStyle> = Box::new(Style::OneUnitFrac);
+    let mut as_json = false;
+    let mut command = vec![];
+    let mut args = Args::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Option(o) => match o.as_str() {
+                "-s" | "--style" => style = args::style(&args.value(&o)?)?,
+                "-j" | "--json" => as_json = true,
+                "-h" | "--help" => {
+                    print!("{}", crate::USAGE);
+                    return Ok(ExitCode::SUCCESS);
+                }
+                _ => return Err(format!("unknown option `{o}`")),
+            },
+            Arg::Value(v) => {
+                command.push(v);
+                command.extend(args.rest());
+            }
+        }
+    }
+    if command.is_empty() {
+        return Err("missing command to run".to_string());
+    }
+    let report = match time(&command) {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("folktime: cannot run `{}`: {e}", command[0]);
+            return Ok(ExitCode::from(127));
+        }
+    };
+    if as_json {
+        eprintln!("{}", json(&command, &report, &style));
+    } else {
+        eprintln!("{}", text(&report, &style));
+    }
+    Ok(ExitCode::from(exit_code(report.status)))
+}
+
+#[cfg(all(
+    test,
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64"
+    )
+))]
+mod tests {
+    use super::*;
+    use std::sync::{Mutex, MutexGuard, PoisonError};
+
+    /// Held while timing, as [time] changes the process-wide Ctrl-C handler.
+    static SIGNALS: Mutex<()> = Mutex::new(());
+
+    fn signals() -> MutexGuard<'static, ()> {
+        SIGNALS.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn sh(script: &str) -> Vec<String> {
+        ["sh", "-c", script].map(String::from).to_vec()
+    }
+
+    #[test]
+    fn status() {
+        let _signals = signals();
+        let report = time(&sh("exit 3")).unwrap();
+        assert_eq!(exit_code(report.status), 3);
+        let report = time(&sh("kill -9 $$")).unwrap();
+        assert_eq!(exit_code(report.status), 128 + 9);
+        // A child started with Ctrl-C ignored, e.g. under a runner that ignores it, is not
+        // stopped by it.
+        if !rusage::Interrupts::ignore().unwrap().were_ignored() {
+            let report = time(&sh("kill -INT $$")).unwrap();
+            assert_eq!(exit_code(report.status), 128 + 2);
+        }
+    }
+    #[test]
+    fn measures() {
+        let _signals = signals();
+        let report = time(&sh("sleep 0.05")).unwrap();
+        assert!(report.wall >= Duration::from_millis(50));
+        assert!(report.cpu.is_some());
+    }
+    #[test]
+    fn measures_cpu() {
+        let _signals = signals();
+        let report = time(&sh("i=0; while [ $i -lt 100000 ]; do i=$((i+1)); done")).unwrap();
+        let (user, sys) = report.cpu.unwrap();
+        assert!(user + sys > Duration::ZERO);
+    }
+    #[test]
+    fn formats() {
+        let _signals = signals();
+        let report = Report {
+            status: time(&sh("true")).unwrap().status,
+            wall: Duration::from_millis(12_345),
+            cpu: Some((Duration::from_millis(1_200), Duration::from_micros(45_000))),
+        };
+        assert_eq!(
+            text(&report, &Style::OneUnitFrac),
+            "real 12.3s\nuser 1.20s\nsys  45.0ms"
+        );
+        assert_eq!(
+            json(&["true".to_string()], &report, &Style::OneUnitFrac),
+            concat!(
+                r#"{"command":["true"],"status":0,"#,
+                r#""real":{"ns":12345000000,"folk":"12.3s"},"#,
+                r#""user":{"ns":1200000000,"folk":"1.20s"},"#,
+                r#""sys":{"ns":45000000,"folk":"45.0ms"}}"#
+            )
+        );
+    }
+}
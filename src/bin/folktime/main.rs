@@ -0,0 +1,107 @@
+//! Command-line interface to folktime.
+
+mod age;
+mod annotate;
+mod args;
+mod convert;
+mod exec;
+mod json;
+mod parse;
+mod timer;
+mod timestamp;
+mod uptime;
+
+use std::io::BufRead;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: folktime [OPTIONS] [NUMBER]...
+       folktime parse [OPTIONS] [DURATION]...
+       folktime exec [OPTIONS] [--] <COMMAND>...
+       folktime annotate [OPTIONS] [FILE]...
+       folktime age [OPTIONS] <PATH>...
+       folktime uptime [OPTIONS] [PID]...
+       folktime timer [OPTIONS] <DURATION>...
+       folktime stopwatch [OPTIONS]
+
+Formats raw numbers as human-friendly durations, or parses durations back
+into numbers. Inputs are read from the arguments, or from the lines of
+standard input when there are none.
+
+Commands:
+  parse    Parse durations such as `2.05m` or `1h 2m` into numbers
+  exec     Run a command and report its real, user and system time on
+           standard error, exiting with the command's exit status
+  annotate Rewrite Unix epoch (s, ms, us, ns, optionally marked with `@`)
+           and RFC 3339 timestamps in log lines as relative times, e.g.
+           `3.20m ago`
+  age      Show how long ago files were modified
+  uptime   Show how long the system, or the given processes, have been up
+  timer    Count down on a single line and ring the bell when done
+  stopwatch
+           Count up on a single line; any key records a lap with its
+           split time, `q` or end of input stops. Piped input, and terminals
+           other than Linux, record a lap per line
+
+Options:
+  -u, --unit <UNIT>    Unit of the numbers: ns, us, ms, s [default: s]
+  -s, --style <STYLE>  Formatting style [default: one-unit-frac]
+  -j, --json           Report `exec` timings as JSON
+  -a, --ago            Make `annotate` rewrite timestamps as relative times
+                       [default]
+  -d, --delta          Make `annotate` add the time since the previous line's
+                       timestamp after the first timestamp of each line
+  -n, --now <TIME>     Reference time for `annotate` and `age`
+                       [default: current time]
+  -h, --help           Print this help
+";
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1).peekable();
+    let result = match args.peek().map(String::as_str) {
+        Some("parse") => parse::run(args.skip(1)),
+        Some("exec") => exec::run(args.skip(1)),
+        Some("annotate") => annotate::run(args.skip(1)),
+        Some("age") => age::run(args.skip(1)),
+        Some("uptime") => uptime::run(args.skip(1)),
+        Some("timer") => timer::timer(args.skip(1)),
+        Some("stopwatch") => timer::stopwatch(args.skip(1)),
+        _ => convert::run(args),
+    };
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("folktime: {e}");
+            eprintln!("Try `folktime --help` for more information.");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Run `f` on every input, reading lines of standard input if `inputs` is empty.
+///
+/// Errors are reported and processing continues with the next input.
+fn for_each_input(inputs: Vec<String>, mut f: impl FnMut(&str) -> Result<(), String>) -> ExitCode {
+    let mut code = ExitCode::SUCCESS;
+    let mut run = |input: &str| {
+        if let Err(e) = f(input) {
+            eprintln!("folktime: {e}");
+            code = ExitCode::FAILURE;
+        }
+    };
+    if inputs.is_empty() {
+        for line in std::io::stdin().lock().lines() {
+            match line {
+                Ok(line) if line.trim().is_empty() => {}
+                Ok(line) => run(line.trim()),
+                Err(e) => {
+                    eprintln!("folktime: {e}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    } else {
+        inputs.iter().for_each(|input| run(input));
+    }
+    code
+}
@@ -0,0 +1,61 @@
+use crate::args::{self, Arg, Args};
+use folktime::duration::Unit;
+use std::process::ExitCode;
+
+/// `d` as an exact decimal number of `unit`s, which must be a power of ten nanoseconds.
+pub fn decimal(d: std::time::Duration, unit: Unit) -> String {
+    let nanos = d.as_nanos();
+    let unit_nanos = unit.as_nanos();
+    let (whole, rest) = (nanos / unit_nanos, nanos % unit_nanos);
+    if rest == 0 {
+        return whole.to_string();
+    }
+    let digits = unit_nanos.ilog10() as usize;
+    let frac = format!("{rest:0digits$}");
+    format!("{whole}.{}", frac.trim_end_matches('0'))
+}
+
+pub fn run(args: impl Iterator<Item = String>) -> Result<ExitCode, String> {
+    let mut unit = Unit::Second;
+    let mut inputs = vec![];
+    let mut args = Args::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Option(o) => match o.as_str() {
+                "-u" | "--unit" => unit = args::number_unit(&args.value(&o)?)?,
+                "-h" | "--help" => {
+                    print!("{}", crate::USAGE);
+                    return Ok(ExitCode::SUCCESS);
+                }
+                _ => return Err(format!("unknown option `{o}`")),
+            },
+            Arg::Value(v) => inputs.push(v),
+        }
+    }
+    Ok(crate::for_each_input(inputs, |input| {
+        let d = folktime::duration::parse(input).map_err(|e| format!("{e} in `{input}`"))?;
+        println!("{}", decimal(d, unit));
+        Ok(())
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn decimals() {
+        assert_eq!(decimal(Duration::from_secs(123), Unit::Second), "123");
+        assert_eq!(decimal(Duration::from_millis(1500), Unit::Second), "1.5");
+        assert_eq!(
+            decimal(Duration::from_nanos(1), Unit::Second),
+            "0.000000001"
+        );
+        assert_eq!(decimal(Duration::from_nanos(1), Unit::Microsecond), "0.001");
+        assert_eq!(
+            decimal(Duration::from_millis(1500), Unit::Nanosecond),
+            "1500000000"
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use crate::args::{self, Arg, Args};
+use folktime::{
+    duration::{DurationStyle, Style},
+    Folktime,
+};
+use std::io::{BufRead, Read, Write};
+use std::process::ExitCode;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+/// How often the live line is redrawn.
+const TICK: Duration = Duration::from_millis(50);
+
+/// Redraws a single terminal line in place.
+///
+/// The line never gets narrower, so values don't jump around when the unit or the
+/// number of digits changes.
+pub struct Line {
+    width: usize,
+}
+
+impl Line {
+    pub fn new() -> Self {
+        Self { width: 0 }
+    }
+
+    /// `d` right-aligned to the widest value shown so far.
+    pub fn format(&mut self, d: Duration, style: &dyn DurationStyle) -> String {
+        let text = Folktime::duration(d).with_style(style).to_string();
+        self.width = self.width.max(text.chars().count());
+        format!("{text:>width$}", width = self.width)
+    }
+
+    fn draw(&self, out: &mut impl Write, text: &str) -> std::io::Result<()> {
+        write!(out, "\r{text}\x1b[K")?;
+        out.flush()
+    }
+}
+
+/// A lap record: its number, its split time and the total time.
+pub fn lap(n: usize, split: Duration, total: Duration, style: &dyn DurationStyle) -> String {
+    let split = Folktime::duration(split).with_style(style);
+    let total = Folktime::duration(total).with_style(style);
+    format!("lap {n:<3} {split:>6}  total {total:>6}")
+}
+
+/// The style and the values given to `timer` or `stopwatch`.
+struct Options {
+    style: Box<dyn DurationStyle>,
+    values: Vec<String>,
+}
+
+/// The parsed options, or `None` if help was printed.
+fn options(args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut style: Box<dyn DurationStyle> = Box::new(Style::OneUnitFrac);
+    let mut values = vec![];
+    let mut args = Args::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Option(o) => match o.as_str() {
+                "-s" | "--style" => style = args::style(&args.value(&o)?)?,
+                "-h" | "--help" => {
+                    print!("{}", crate::USAGE);
+                    return Ok(None);
+                }
+                _ => return Err(format!("unknown option `{o}`")),
+            },
+            Arg::Value(v) => values.push(v),
+        }
+    }
+    Ok(Some(Options { style, values }))
+}
+
+/// `folktime timer <DURATION>`: count down and ring the bell when done.
+pub fn timer(args: impl Iterator<Item = String>) -> Result<ExitCode, String> {
+    let Some(Options { style, values }) = options(args)? else {
+        return Ok(ExitCode::SUCCESS);
+    };
+    let total = folktime::duration::parse(&values.join(" ")).map_err(|e| e.to_string())?;
+    let start = Instant::now();
+    let mut line = Line::new();
+    let mut out = std::io::stdout().lock();
+    loop {
+        let remaining = total.saturating_sub(start.elapsed());
+        let text = line.format(remaining, &style);
+        line.draw(&mut out, &text).map_err(|e| e.to_string())?;
+        if remaining.is_zero() {
+            writeln!(out, "  done\x07").map_err(|e| e.to_string())?;
+            return Ok(ExitCode::SUCCESS);
+        }
+        std::thread::sleep(TICK.min(remaining));
+    }
+}
+
+// The struct layout and constants below are those of the generic Linux ABI. Other
+// architectures, such as powerpc, mips and sparc, read input line by line.
+#[cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64"
+    )
+))]
+mod terminal {
+    use std::ffi::{c_int, c_uint};
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct Termios {
+        c_iflag: c_uint,
+        c_oflag: c_uint,
+        c_cflag: c_uint,
+        c_lflag: c_uint,
+        c_line: u8,
+        c_cc: [u8; 32],
+        c_ispeed: c_uint,
+        c_ospeed: c_uint,
+    }
+
+    const ISIG: c_uint = 0o1;
+    const ICANON: c_uint = 0o2;
+    const ECHO: c_uint = 0o10;
+    const VTIME: usize = 5;
+    const VMIN: usize = 6;
+    const TCSANOW: c_int = 0;
+
+    extern "C" {
+        fn isatty(fd: c_int) -> c_int;
+        fn tcgetattr(fd: c_int, termios: *mut Termios) -> c_int;
+        fn tcsetattr(fd: c_int, action: c_int, termios: *const Termios) -> c_int;
+    }
+
+    /// Standard input switched to reading single keypresses without echo, restored on drop.
+    ///
+    /// Ctrl-C arrives as a key too, so the terminal is restored when it stops the stopwatch.
+    pub struct RawMode(Termios);
+
+    impl RawMode {
+        /// `None` if standard input is not a terminal.
+        pub fn enable() -> Option<Self> {
+            let mut termios = std::mem::MaybeUninit::<Termios>::uninit();
+            // SAFETY: `tcgetattr` fills in the struct when it succeeds, and `tcsetattr` only
+            // reads it.
+            unsafe {
+                if isatty(0) != 1 || tcgetattr(0, termios.as_mut_ptr()) != 0 {
+                    return None;
+                }
+                let saved = termios.assume_init();
+                let mut raw = saved;
+                raw.c_lflag &= !(ICANON | ECHO | ISIG);
+                raw.c_cc[VMIN] = 1;
+                raw.c_cc[VTIME] = 0;
+                if tcsetattr(0, TCSANOW, &raw) != 0 {
+                    return None;
+                }
+                Some(Self(saved))
+            }
+        }
+    }
+
+    impl Drop for RawMode {
+        fn drop(&mut self) {
+            // SAFETY: restores the settings read in `enable`.
+            unsafe { tcsetattr(0, TCSANOW, &self.0) };
+        }
+    }
+}
+
+#[cfg(not(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64"
+    )
+)))]
+mod terminal {
+    pub struct RawMode;
+
+    impl RawMode {
+        pub fn enable() -> Option<Self> {
+            None
+        }
+    }
+}
+
+/// A key that stops the stopwatch: `q`, Ctrl-C or Ctrl-D.
+fn is_stop(key: &str) -> bool {
+    matches!(key.trim(), "q" | "\x03" | "\x04")
+}
+
+/// `folktime stopwatch`: count up, recording a lap on every keypress until `q` or end of
+/// input.
+///
+/// On a Linux terminal any key records a lap. Elsewhere, and when input is piped, laps are
+/// read line by line, so they need Enter.
+pub fn stopwatch(args: impl Iterator<Item = String>) -> Result<ExitCode, String> {
+    let Some(Options { style, values }) = options(args)? else {
+        return Ok(ExitCode::SUCCESS);
+    };
+    if let Some(value) = values.first() {
+        return Err(format!("unexpected argument `{value}`"));
+    }
+    let raw = terminal::RawMode::enable();
+    let (keys, presses) = mpsc::channel();
+    let keypresses = raw.is_some();
+    std::thread::spawn(move || {
+        if keypresses {
+            // A read returns one keypress, including all bytes of e.g. an arrow key.
+            let mut buf = [0; 16];
+            while let Ok(n @ 1..) = std::io::stdin().lock().read(&mut buf) {
+                let key = String::from_utf8_lossy(&buf[..n]).into_owned();
+                if keys.send(key).is_err() {
+                    break;
+                }
+            }
+        } else {
+            for line in std::io::stdin().lock().lines() {
+                let Ok(line) = line else { break };
+                if keys.send(line).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+    let start = Instant::now();
+    let mut last_lap = Duration::ZERO;
+    let mut laps = 0;
+    let mut line = Line::new();
+    let mut out = std::io::stdout().lock();
+    let draw = |out: &mut std::io::StdoutLock, line: &mut Line| {
+        let text = line.format(start.elapsed(), &style);
+        line.draw(out, &text)
+    };
+    loop {
+        match presses.recv_timeout(TICK) {
+            Ok(key) if is_stop(&key) => break,
+            Ok(_) => {
+                let total = start.elapsed();
+                laps += 1;
+                let record = lap(laps, total - last_lap, total, &style);
+                if raw.is_none() {
+                    // The echoed Enter moved the cursor down, go back to the live line.
+                    write!(out, "\x1b[1A").map_err(|e| e.to_string())?;
+                }
+                write!(out, "\r{record}\x1b[K\n").map_err(|e| e.to_string())?;
+                last_lap = total;
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+        draw(&mut out, &mut line).map_err(|e| e.to_string())?;
+    }
+    draw(&mut out, &mut line).map_err(|e| e.to_string())?;
+    writeln!(out).map_err(|e| e.to_string())?;
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_never_shrinks() {
+        let mut line = Line::new();
+        let style = Style::OneUnitFrac;
+        assert_eq!(line.format(Duration::from_millis(10_500), &style), "10.5s");
+        assert_eq!(line.format(Duration::from_millis(9_500), &style), "9.50s");
+        assert_eq!(line.format(Duration::from_millis(999), &style), "999ms");
+        assert_eq!(line.format(Duration::from_millis(10), &style), "10.0ms");
+        assert_eq!(line.format(Duration::from_nanos(5), &style), "   5ns");
+    }
+    #[test]
+    fn stop_keys() {
+        assert!(is_stop("q"));
+        assert!(is_stop("q\n"));
+        assert!(is_stop("\x03"));
+        assert!(!is_stop(" "));
+        assert!(!is_stop("\x1b[A"));
+    }
+    #[test]
+    fn laps() {
+        let style = Style::OneUnitFrac;
+        assert_eq!(
+            lap(
+                1,
+                Duration::from_millis(1_500),
+                Duration::from_millis(1_500),
+                &style
+            ),
+            "lap 1    1.50s  total  1.50s"
+        );
+        assert_eq!(
+            lap(
+                12,
+                Duration::from_secs(61),
+                Duration::from_secs(600),
+                &style
+            ),
+            "lap 12   1.01m  total  10.0m"
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Parses fixed-width fields from the start of a string.
+struct Cursor<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl Cursor<'_> {
+    fn digits(&mut self, n: usize) -> Option<i64> {
+        let digits = self.s.get(self.pos..self.pos + n)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        self.pos += n;
+        Some(digits.iter().fold(0, |v, d| v * 10 + (d - b'0') as i64))
+    }
+
+    fn byte(&mut self, accept: impl Fn(u8) -> bool) -> Option<u8> {
+        let b = *self.s.get(self.pos).filter(|b| accept(**b))?;
+        self.pos += 1;
+        Some(b)
+    }
+}
+
+fn add_signed(t: SystemTime, secs: i64, nanos: u32) -> Option<SystemTime> {
+    let t = if secs >= 0 {
+        t.checked_add(Duration::from_secs(secs as u64))?
+    } else {
+        t.checked_sub(Duration::from_secs(secs.unsigned_abs()))?
+    };
+    t.checked_add(Duration::from_nanos(nanos as u64))
+}
+
+/// An RFC 3339 timestamp at the start of `s`, e.g. `2024-05-01T12:30:00.25+02:00`,
+/// and its length.
+///
+/// A space may separate the date and the time, and a missing offset means UTC.
+pub fn rfc3339(s: &str) -> Option<(SystemTime, usize)> {
+    let mut c = Cursor {
+        s: s.as_bytes(),
+        pos: 0,
+    };
+    let year = c.digits(4)?;
+    c.byte(|b| b == b'-')?;
+    let month = c.digits(2)?;
+    c.byte(|b| b == b'-')?;
+    let day = c.digits(2)?;
+    c.byte(|b| matches!(b, b'T' | b't' | b' '))?;
+    let hour = c.digits(2)?;
+    c.byte(|b| b == b':')?;
+    let minute = c.digits(2)?;
+    c.byte(|b| b == b':')?;
+    let second = c.digits(2)?;
+    if !(1..=12).contains(&month) || hour > 23 || minute > 59 {
+        return None;
+    }
+    if !(1..=days_in_month(year, month)).contains(&day) {
+        return None;
+    }
+    if second > 60 {
+        return None;
+    }
+    let mut nanos = 0u32;
+    if c.byte(|b| b == b'.').is_some() {
+        let start = c.pos;
+        while c.byte(|b| b.is_ascii_digit()).is_some() {}
+        let frac = &s[start..c.pos];
+        if frac.is_empty() {
+            return None;
+        }
+        let frac = format!("{:0<9}", &frac[..frac.len().min(9)]);
+        nanos = frac.parse().ok()?;
+    }
+    let mut offset = 0;
+    if c.byte(|b| matches!(b, b'Z' | b'z')).is_none() {
+        if let Some(sign) = c.byte(|b| matches!(b, b'+' | b'-')) {
+            let hours = c.digits(2)?;
+            c.byte(|b| b == b':')?;
+            let minutes = c.digits(2)?;
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            offset = (hours * 60 + minutes) * 60;
+            if sign == b'-' {
+                offset = -offset;
+            }
+        }
+    }
+    let days = days_from_civil(year, month, day);
+    let secs = days * 86_400 + hour * 3_600 + minute * 60 + second - offset;
+    Some((add_signed(UNIX_EPOCH, secs, nanos)?, c.pos))
+}
+
+/// A Unix epoch timestamp at the start of `s`, e.g. `1714566600` or `@1714566600`, and
+/// its length.
+///
+/// The precision is told by the number of digits: 10 for seconds, optionally with a
+/// fraction, 13 for milliseconds, 16 for microseconds and 19 for nanoseconds.
+pub fn epoch(s: &str) -> Option<(SystemTime, usize)> {
+    let (marker, s) = match s.strip_prefix('@') {
+        Some(s) => (1, s),
+        None => (0, s),
+    };
+    let len = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let value: u64 = s[..len].parse().ok()?;
+    let (d, len) = match len {
+        10 => {
+            let frac_len = s[len..]
+                .strip_prefix('.')
+                .map(|rest| {
+                    rest.find(|c: char| !c.is_ascii_digit())
+                        .unwrap_or(rest.len())
+                })
+                .filter(|n| *n > 0)
+                .unwrap_or(0);
+            if frac_len == 0 {
+                (Duration::from_secs(value), len)
+            } else {
+                let frac = &s[len + 1..len + 1 + frac_len];
+                let nanos = format!("{:0<9}", &frac[..frac_len.min(9)]).parse().ok()?;
+                (Duration::new(value, nanos), len + 1 + frac_len)
+            }
+        }
+        13 => (Duration::from_millis(value), len),
+        16 => (Duration::from_micros(value), len),
+        19 => (Duration::from_nanos(value), len),
+        _ => return None,
+    };
+    Some((UNIX_EPOCH.checked_add(d)?, marker + len))
+}
+
+/// A timestamp at the start of `s`, in any of the supported formats, and its length.
+pub fn parse_prefix(s: &str) -> Option<(SystemTime, usize)> {
+    rfc3339(s).or_else(|| epoch(s))
+}
+
+/// A string that is exactly one timestamp.
+pub fn parse(s: &str) -> Option<SystemTime> {
+    parse_prefix(s)
+        .filter(|(_, len)| *len == s.len())
+        .map(|(t, _)| t)
+}
+
+/// The timestamps in `line`, as byte ranges with their times.
+///
+/// Timestamps must not be directly preceded or followed by letters or digits, so that
+/// numbers inside words are left alone.
+pub fn find(line: &str) -> Vec<(std::ops::Range<usize>, SystemTime)> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut found = vec![];
+    let mut i = 0;
+    while i < line.len() {
+        let rest = &line[i..];
+        // An `@` only marks a timestamp at the start of one.
+        let before = line[..i].chars().next_back();
+        let at_boundary = !before.is_some_and(|c| is_word(c) || c == '@');
+        if at_boundary && rest.starts_with(|c: char| c.is_ascii_digit() || c == '@') {
+            if let Some((t, len)) = parse_prefix(rest) {
+                let after = rest[len..].chars().next();
+                if !after.is_some_and(is_word) {
+                    found.push((i..i + len, t));
+                    i += len;
+                    continue;
+                }
+            }
+        }
+        i += rest.chars().next().map_or(1, char::len_utf8);
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn epoch_secs(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn rfc3339_formats() {
+        let t = epoch_secs(1_714_566_600);
+        assert_eq!(rfc3339("2024-05-01T12:30:00Z"), Some((t, 20)));
+        assert_eq!(rfc3339("2024-05-01 12:30:00"), Some((t, 19)));
+        assert_eq!(rfc3339("2024-05-01T14:30:00+02:00"), Some((t, 25)));
+        assert_eq!(rfc3339("2024-05-01T07:30:00-05:00 x"), Some((t, 25)));
+        let frac = t + Duration::from_millis(250);
+        assert_eq!(rfc3339("2024-05-01T12:30:00.25Z"), Some((frac, 23)));
+        assert_eq!(
+            rfc3339("1969-12-31T23:59:59Z"),
+            Some((epoch_secs(0) - Duration::from_secs(1), 20))
+        );
+    }
+    #[test]
+    fn rfc3339_invalid() {
+        assert_eq!(rfc3339("2024-13-01T12:30:00Z"), None);
+        assert_eq!(rfc3339("2024-05-01T12:30Z"), None);
+        assert_eq!(rfc3339("2024-05-01"), None);
+        assert_eq!(rfc3339("2024-05-01T12:30:00."), None);
+        assert_eq!(rfc3339("2024-02-31T12:30:00Z"), None);
+        assert_eq!(rfc3339("2023-02-29T12:30:00Z"), None);
+        assert_eq!(rfc3339("2024-04-31T12:30:00Z"), None);
+        assert!(rfc3339("2024-02-29T12:30:00Z").is_some());
+        assert!(rfc3339("2000-02-29T12:30:00Z").is_some());
+        assert_eq!(rfc3339("1900-02-29T12:30:00Z"), None);
+        assert_eq!(rfc3339("2024-05-01T12:30:00+99:99"), None);
+        assert_eq!(rfc3339("2024-05-01T12:30:00-24:00"), None);
+        assert_eq!(rfc3339("2024-05-01T12:30:00+05:60"), None);
+        assert!(rfc3339("2024-05-01T12:30:00-23:59").is_some());
+    }
+    #[test]
+    fn epochs() {
+        let t = epoch_secs(1_714_566_600);
+        assert_eq!(epoch("@1714566600"), Some((t, 11)));
+        assert_eq!(
+            epoch("@1714566600.5 x"),
+            Some((t + Duration::from_millis(500), 13))
+        );
+        assert_eq!(epoch("@1714566600. x"), Some((t, 11)));
+        assert_eq!(epoch("@1714566600000"), Some((t, 14)));
+        assert_eq!(epoch("@1714566600000000"), Some((t, 17)));
+        assert_eq!(epoch("@1714566600000000000"), Some((t, 20)));
+        assert_eq!(epoch("1714566600 x"), Some((t, 10)));
+        assert_eq!(epoch("1714566600000"), Some((t, 13)));
+        assert_eq!(epoch("@171456660"), None);
+        assert_eq!(epoch("17145666000"), None);
+    }
+    #[test]
+    fn finds() {
+        let line = "1714566600 start, @1714566600000 at 2024-05-01T12:30:00Z;";
+        let ranges: Vec<_> = find(line).into_iter().map(|(r, _)| r).collect();
+        assert_eq!(ranges, [0..10, 18..32, 36..56]);
+        assert!(find("v@1714566600 x1714566600 1714566600x 42").is_empty());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::args::{self, Arg, Args};
+use folktime::{
+    duration::{DurationStyle, Style},
+    Folktime,
+};
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Time since the system booted, from `/proc/uptime`.
+pub fn system() -> Result<Duration, String> {
+    let uptime =
+        std::fs::read_to_string("/proc/uptime").map_err(|e| format!("/proc/uptime: {e}"))?;
+    let secs = uptime.split_whitespace().next().unwrap_or_default();
+    folktime::duration::parse(&format!("{secs}s"))
+        .map_err(|_| format!("/proc/uptime: invalid uptime `{secs}`"))
+}
+
+#[cfg(target_os = "linux")]
+fn clock_ticks() -> u64 {
+    extern "C" {
+        fn sysconf(name: std::ffi::c_int) -> std::ffi::c_long;
+    }
+    const SC_CLK_TCK: std::ffi::c_int = 2;
+    // SAFETY: `sysconf` has no preconditions.
+    match unsafe { sysconf(SC_CLK_TCK) } {
+        ticks if ticks > 0 => ticks as u64,
+        _ => 100,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn clock_ticks() -> u64 {
+    100
+}
+
+/// Time since the process `pid` started, from `/proc/<pid>/stat`.
+pub fn process(pid: u32) -> Result<Duration, String> {
+    let path = format!("/proc/{pid}/stat");
+    let stat = std::fs::read_to_string(&path).map_err(|e| format!("{path}: {e}"))?;
+    // The command name may contain spaces and parentheses, fields follow the last `)`.
+    let fields = stat.rsplit_once(')').map(|(_, fields)| fields);
+    let start = fields
+        .and_then(|fields| fields.split_whitespace().nth(19))
+        .and_then(|ticks| ticks.parse::<u64>().ok())
+        .ok_or_else(|| format!("{path}: missing start time"))?;
+    let ticks = clock_ticks();
+    let start = Duration::from_secs(start / ticks)
+        + Duration::from_nanos(start % ticks * 1_000_000_000 / ticks);
+    Ok(system()?.saturating_sub(start))
+}
+
+pub fn run(args: impl Iterator<Item = String>) -> Result<ExitCode, String> {
+    let mut style: Box<dyn DurationStyle> = Box::new(Style::OneUnitFrac);
+    let mut pids = vec![];
+    let mut args = Args::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Option(o) => match o.as_str() {
+                "-s" | "--style" => style = args::style(&args.value(&o)?)?,
+                "-h" | "--help" => {
+                    print!("{}", crate::USAGE);
+                    return Ok(ExitCode::SUCCESS);
+                }
+                _ => return Err(format!("unknown option `{o}`")),
+            },
+            Arg::Value(v) => {
+                let pid = v.parse::<u32>().map_err(|_| format!("invalid pid `{v}`"))?;
+                pids.push(pid);
+            }
+        }
+    }
+    let mut code = ExitCode::SUCCESS;
+    let mut show = |uptime: Result<Duration, String>, pid: Option<u32>| match uptime {
+        Ok(d) => {
+            let uptime = Folktime::duration(d).with_style(&style);
+            match pid {
+                Some(pid) => println!("{uptime}\t{pid}"),
+                None => println!("{uptime}"),
+            }
+        }
+        Err(e) => {
+            eprintln!("folktime: {e}");
+            code = ExitCode::FAILURE;
+        }
+    };
+    match pids.as_slice() {
+        [] => show(system(), None),
+        pids => pids.iter().for_each(|pid| show(process(*pid), Some(*pid))),
+    }
+    Ok(code)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn system_uptime() {
+        assert!(system().unwrap() > Duration::ZERO);
+    }
+    #[test]
+    fn own_process() {
+        let own = process(std::process::id()).unwrap();
+        assert!(own <= system().unwrap());
+        assert!(own < Duration::from_secs(3600));
+    }
+    #[test]
+    fn missing_process() {
+        assert!(process(u32::MAX).is_err());
+    }
+}
@@ -0,0 +1,61 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+/// A source of the current time, so that timing can be tested without waiting.
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+/// The real clock, [Instant::now].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock that only moves when told to.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::clock::{Clock, ManualClock};
+///
+/// let clock = ManualClock::new();
+/// let start = clock.now();
+/// clock.advance(Duration::from_secs(5));
+/// assert_eq!(clock.now() - start, Duration::from_secs(5));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ManualClock(Cell<Instant>);
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self(Cell::new(Instant::now()))
+    }
+
+    /// Move the clock forward by `d`.
+    pub fn advance(&self, d: Duration) {
+        self.0.set(self.0.get() + d);
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.0.get()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+}
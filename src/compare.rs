@@ -0,0 +1,184 @@
+use crate::duration::{fmt_quotient, Duration, DurationStyle, Style};
+use std::fmt::Display;
+
+/// A candidate duration compared to a baseline, formatted as e.g.
+/// `12.3ms → 15.1ms (+2.80ms, 1.22× slower)`.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::Folktime;
+///
+/// let ms = |micros| Duration::from_micros(micros);
+/// let a = Folktime::compare(ms(12_300), ms(15_100));
+/// let b = Folktime::compare(ms(15_100), ms(12_300));
+///
+/// assert_eq!(format!("{}", a), "12.3ms → 15.1ms (+2.80ms, 1.22× slower)");
+/// assert_eq!(format!("{}", b), "15.1ms → 12.3ms (-2.80ms, 1.22× faster)");
+/// ```
+pub struct Comparison<S = Style> {
+    baseline: Duration<S>,
+    candidate: std::time::Duration,
+    slower: &'static str,
+    faster: &'static str,
+}
+
+impl Comparison {
+    pub fn new(baseline: std::time::Duration, candidate: std::time::Duration) -> Self {
+        Self {
+            baseline: Duration::new(baseline),
+            candidate,
+            slower: "slower",
+            faster: "faster",
+        }
+    }
+}
+
+impl<S> Comparison<S> {
+    /// Set the formatting style of the durations.
+    pub fn with_style<T: DurationStyle>(self, style: T) -> Comparison<T> {
+        Comparison {
+            baseline: self.baseline.with_style(style),
+            candidate: self.candidate,
+            slower: self.slower,
+            faster: self.faster,
+        }
+    }
+
+    /// Set the words used when the candidate is longer or shorter than the baseline,
+    /// `"slower"` and `"faster"` by default.
+    pub fn with_words(self, slower: &'static str, faster: &'static str) -> Self {
+        Self {
+            slower,
+            faster,
+            ..self
+        }
+    }
+
+    pub fn baseline(&self) -> std::time::Duration {
+        self.baseline.0
+    }
+
+    pub fn candidate(&self) -> std::time::Duration {
+        self.candidate
+    }
+
+    /// Whether the candidate is shorter than the baseline.
+    pub fn is_faster(&self) -> bool {
+        self.candidate < self.baseline.0
+    }
+
+    /// Absolute difference between the candidate and the baseline.
+    pub fn delta(&self) -> std::time::Duration {
+        self.candidate.abs_diff(self.baseline.0)
+    }
+
+    /// How many times longer the candidate is than the baseline, if the baseline isn't zero.
+    pub fn ratio(&self) -> Option<f64> {
+        if self.baseline.0.is_zero() {
+            None
+        } else {
+            Some(self.candidate.as_secs_f64() / self.baseline.0.as_secs_f64())
+        }
+    }
+}
+
+/// Writes `long / short` with 3 significant digits, truncated, e.g. `1.22×`.
+///
+/// From 1000 on, the ratio is written in scientific notation, e.g. `1.84e19×`.
+fn fmt_ratio(
+    long: std::time::Duration,
+    short: std::time::Duration,
+    f: &mut std::fmt::Formatter,
+) -> std::fmt::Result {
+    let (long, short) = (long.as_nanos(), short.as_nanos());
+    let whole = long / short;
+    if whole < 1000 {
+        fmt_quotient(long, short, f)?;
+    } else {
+        let exp = whole.ilog10();
+        let digits = whole / 10u128.pow(exp - 2);
+        write!(f, "{}.{:02}e{exp}", digits / 100, digits % 100)?;
+    }
+    f.write_str("×")
+}
+
+impl<S: DurationStyle> Display for Comparison<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let style = &self.baseline.1;
+        let sign = if self.is_faster() { '-' } else { '+' };
+        write!(
+            f,
+            "{} → {} ({sign}{}",
+            self.baseline,
+            Duration(self.candidate, style),
+            Duration(self.delta(), style),
+        )?;
+        if self.candidate == self.baseline.0 {
+            return write!(f, ", same)");
+        }
+        let (word, long, short) = if self.is_faster() {
+            (self.faster, self.baseline.0, self.candidate)
+        } else {
+            (self.slower, self.candidate, self.baseline.0)
+        };
+        f.write_str(", ")?;
+        if !short.is_zero() {
+            fmt_ratio(long, short, f)?;
+            f.write_str(" ")?;
+        }
+        write!(f, "{word})")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{duration::Style, Folktime};
+    use std::time::Duration;
+
+    fn compare(baseline_ms: u64, candidate_ms: u64) -> String {
+        Folktime::compare(
+            Duration::from_millis(baseline_ms),
+            Duration::from_millis(candidate_ms),
+        )
+        .to_string()
+    }
+
+    #[test]
+    fn slower_and_faster() {
+        assert_eq!(compare(100, 150), "100ms → 150ms (+50.0ms, 1.50× slower)");
+        assert_eq!(compare(150, 100), "150ms → 100ms (-50.0ms, 1.50× faster)");
+        assert_eq!(compare(10, 1_000), "10.0ms → 1.00s (+990ms, 100× slower)");
+        assert_eq!(compare(1_000, 64), "1.00s → 64.0ms (-936ms, 15.6× faster)");
+        assert_eq!(compare(1, 999), "1.00ms → 999ms (+998ms, 999× slower)");
+        assert_eq!(compare(1, 1_000), "1.00ms → 1.00s (+999ms, 1.00e3× slower)");
+        assert_eq!(
+            compare(3, 123_456),
+            "3.00ms → 2.05m (+2.05m, 4.11e4× slower)"
+        );
+    }
+    #[test]
+    fn huge_ratio() {
+        let c = Folktime::compare(Duration::from_nanos(1), Duration::MAX);
+        assert_eq!(c.to_string(), "1ns → 584Gy (+584Gy, 1.84e28× slower)");
+    }
+    #[test]
+    fn same() {
+        assert_eq!(compare(100, 100), "100ms → 100ms (+0.00s, same)");
+    }
+    #[test]
+    fn zero() {
+        assert_eq!(compare(0, 5), "0.00s → 5.00ms (+5.00ms, slower)");
+        assert_eq!(compare(5, 0), "5.00ms → 0.00s (-5.00ms, faster)");
+    }
+    #[test]
+    fn options() {
+        let c = Folktime::compare(Duration::from_secs(3_600), Duration::from_secs(5_430))
+            .with_style(Style::TwoUnitsWhole)
+            .with_words("longer", "shorter");
+        assert_eq!(c.to_string(), "1h 0m → 1h 30m (+30m 30s, 1.50× longer)");
+        assert_eq!(c.delta(), Duration::from_secs(1_830));
+        assert_eq!(c.ratio(), Some(1.5083333333333333));
+        assert!(!c.is_faster());
+    }
+}
@@ -0,0 +1,215 @@
+use crate::clock::Clock;
+use crate::duration::{first_change, from_nanos, Duration, DurationStyle, Style};
+use std::fmt::Display;
+use std::time::{Instant, SystemTime};
+
+/// A deadline seen from a point in time, formatted as e.g. `due in 3.20h` or
+/// `overdue by 12.0m`.
+///
+/// Within the "due now" window around the deadline, zero by default, it is formatted as
+/// `due now`.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::clock::{Clock, ManualClock};
+/// use folktime::deadline::Deadline;
+///
+/// let clock = ManualClock::new();
+/// let due = clock.now() + Duration::from_secs(3 * 3_600 + 720);
+///
+/// assert_eq!(Deadline::from_clock(due, &clock).to_string(), "due in 3.20h");
+/// clock.advance(Duration::from_secs(4 * 3_600));
+/// let d = Deadline::from_clock(due, &clock);
+/// assert_eq!(d.to_string(), "overdue by 48.0m");
+/// assert_eq!(d.overdue_by(), Some(Duration::from_secs(48 * 60)));
+/// ```
+pub struct Deadline<S = Style> {
+    offset: Duration<S>,
+    overdue: bool,
+    window: std::time::Duration,
+}
+
+impl Deadline {
+    /// `deadline` as seen from `now`.
+    pub fn new(deadline: Instant, now: Instant) -> Self {
+        match deadline.checked_duration_since(now) {
+            Some(d) => Self::from_offset(d, false),
+            None => Self::from_offset(now - deadline, true),
+        }
+    }
+
+    /// `deadline` as seen from the current time of `clock`.
+    pub fn from_clock<C: Clock>(deadline: Instant, clock: &C) -> Self {
+        Self::new(deadline, clock.now())
+    }
+
+    /// A wall-clock `deadline` as seen from `now`.
+    pub fn from_system_time(deadline: SystemTime, now: SystemTime) -> Self {
+        match deadline.duration_since(now) {
+            Ok(d) => Self::from_offset(d, false),
+            Err(e) => Self::from_offset(e.duration(), true),
+        }
+    }
+
+    fn from_offset(offset: std::time::Duration, overdue: bool) -> Self {
+        Self {
+            offset: Duration::new(offset),
+            overdue: overdue && !offset.is_zero(),
+            window: std::time::Duration::ZERO,
+        }
+    }
+}
+
+impl<S> Deadline<S> {
+    /// Set the formatting style of the time left or overdue.
+    pub fn with_style<T: DurationStyle>(self, style: T) -> Deadline<T> {
+        Deadline {
+            offset: self.offset.with_style(style),
+            overdue: self.overdue,
+            window: self.window,
+        }
+    }
+
+    /// Show `due now` when the deadline is at most `window` away, before or after it.
+    pub fn with_window(self, window: std::time::Duration) -> Self {
+        Self { window, ..self }
+    }
+
+    /// Whether the deadline has passed.
+    pub fn is_overdue(&self) -> bool {
+        self.overdue
+    }
+
+    /// Whether the deadline is within the "due now" window.
+    pub fn is_due_now(&self) -> bool {
+        self.offset.0 <= self.window
+    }
+
+    /// How long ago the deadline passed, if it has.
+    pub fn overdue_by(&self) -> Option<std::time::Duration> {
+        self.overdue.then_some(self.offset.0)
+    }
+
+    /// Time left until the deadline, zero if it has passed.
+    pub fn remaining(&self) -> std::time::Duration {
+        match self.overdue {
+            true => std::time::Duration::ZERO,
+            false => self.offset.0,
+        }
+    }
+}
+
+impl<S: DurationStyle> Deadline<S> {
+    /// How long until the formatted output changes, as time passes, e.g. to schedule the
+    /// next repaint. See [Duration::next_change](crate::duration::Duration::next_change).
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use folktime::deadline::Deadline;
+    ///
+    /// let now = Instant::now();
+    /// let d = Deadline::new(now + Duration::from_millis(5_004), now);
+    /// assert_eq!(d.to_string(), "due in 5.00s");
+    /// assert_eq!(d.next_change(), Some(Duration::from_nanos(4_000_001)));
+    /// ```
+    pub fn next_change(&self) -> Option<std::time::Duration> {
+        let offset = self.offset.0.as_nanos() as i128;
+        let offset = if self.overdue { -offset } else { offset };
+        let max = std::time::Duration::MAX.as_nanos() as i128;
+        let render = |step: u128| {
+            let offset = offset - step as i128;
+            Deadline {
+                offset: Duration(from_nanos(offset.unsigned_abs()), &self.offset.1),
+                overdue: offset < 0,
+                window: self.window,
+            }
+            .to_string()
+        };
+        first_change((offset + max) as u128, render).map(from_nanos)
+    }
+}
+
+impl<S: DurationStyle> Display for Deadline<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_due_now() {
+            write!(f, "due now")
+        } else if self.overdue {
+            write!(f, "overdue by {}", self.offset)
+        } else {
+            write!(f, "due in {}", self.offset)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use std::time::Duration;
+
+    #[test]
+    fn due_in() {
+        let now = Instant::now();
+        let d = Deadline::new(now + Duration::from_secs(90), now);
+        assert_eq!(d.to_string(), "due in 1.50m");
+        assert!(!d.is_overdue());
+        assert_eq!(d.overdue_by(), None);
+        assert_eq!(d.remaining(), Duration::from_secs(90));
+    }
+    #[test]
+    fn overdue() {
+        let clock = ManualClock::new();
+        let due = clock.now() + Duration::from_secs(60);
+        clock.advance(Duration::from_secs(60 + 12 * 60));
+        let d = Deadline::from_clock(due, &clock).with_style(Style::OneUnitWhole);
+        assert_eq!(d.to_string(), "overdue by 12m");
+        assert!(d.is_overdue());
+        assert_eq!(d.overdue_by(), Some(Duration::from_secs(12 * 60)));
+        assert_eq!(d.remaining(), Duration::ZERO);
+    }
+    #[test]
+    fn due_now() {
+        let clock = ManualClock::new();
+        let due = clock.now();
+        let d = Deadline::from_clock(due, &clock);
+        assert_eq!(d.to_string(), "due now");
+        assert!(!d.is_overdue());
+
+        let window = Duration::from_secs(60);
+        clock.advance(Duration::from_secs(59));
+        let d = Deadline::from_clock(due, &clock).with_window(window);
+        assert_eq!(d.to_string(), "due now");
+        assert!(d.is_due_now());
+        assert!(d.is_overdue());
+        clock.advance(Duration::from_secs(2));
+        let d = Deadline::from_clock(due, &clock).with_window(window);
+        assert_eq!(d.to_string(), "overdue by 1.01m");
+        assert_eq!(d.next_change(), Some(Duration::from_millis(200)));
+
+        let now = Instant::now();
+        let d = Deadline::new(now + Duration::from_secs(30), now).with_window(window);
+        assert_eq!(d.to_string(), "due now");
+        assert!(!d.is_overdue());
+        assert_eq!(
+            d.next_change(),
+            Some(Duration::from_secs(90) + Duration::from_nanos(1))
+        );
+        let d = Deadline::new(now + Duration::from_secs(61), now).with_window(window);
+        assert_eq!(d.to_string(), "due in 1.01m");
+        assert_eq!(
+            d.next_change(),
+            Some(Duration::from_millis(400) + Duration::from_nanos(1))
+        );
+    }
+    #[test]
+    fn system_time() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let d = Deadline::from_system_time(now + Duration::from_secs(3 * 3_600 + 60), now)
+            .with_style(Style::TwoUnitsWhole);
+        assert_eq!(d.to_string(), "due in 3h 1m");
+        let d = Deadline::from_system_time(now - Duration::from_secs(5), now);
+        assert_eq!(d.to_string(), "overdue by 5.00s");
+    }
+}
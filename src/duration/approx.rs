@@ -0,0 +1,277 @@
+use super::{Duration, DurationStyle, Part, Parts, Unit, WideUnit};
+
+/// Marks inexact output of another style with a prefix, e.g. `~2.05m`.
+///
+/// Values that the inner style shows exactly are left unmarked.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::Folktime;
+/// use folktime::duration::{Approx, Style};
+///
+/// let a = Folktime::duration(Duration::from_secs(124)).approx();
+/// let b = Folktime::duration(Duration::from_secs(90)).approx();
+/// let c = Folktime::duration(Duration::from_secs(123))
+///     .with_style(Approx::new(Style::OneUnitWhole).with_marker("≈"));
+///
+/// assert_eq!(format!("{}", a), "~2.06m");
+/// assert_eq!(format!("{}", b), "1.50m");
+/// assert_eq!(format!("{}", c), "≈2m");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Approx<S> {
+    style: S,
+    marker: &'static str,
+}
+
+impl<S> Approx<S> {
+    /// Wrap `style`, marking inexact output with `~`.
+    pub const fn new(style: S) -> Self {
+        Self { style, marker: "~" }
+    }
+
+    /// Set the marker written in front of inexact output.
+    pub fn with_marker(self, marker: &'static str) -> Self {
+        Self {
+            style: self.style,
+            marker,
+        }
+    }
+}
+
+impl<S: DurationStyle> DurationStyle for Approx<S> {
+    fn parts(&self, d: std::time::Duration) -> Parts {
+        self.style.parts(d)
+    }
+
+    fn fmt(&self, d: std::time::Duration, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if !self.style.is_exact(d) {
+            f.write_str(self.marker)?;
+        }
+        self.style.fmt(d, f)
+    }
+
+    fn is_exact(&self, d: std::time::Duration) -> bool {
+        self.style.is_exact(d)
+    }
+
+    fn fmt_sub_nanos(&self, attos: u64, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if !self.style.is_exact_sub_nanos(attos) {
+            f.write_str(self.marker)?;
+        }
+        self.style.fmt_sub_nanos(attos, f)
+    }
+
+    fn is_exact_sub_nanos(&self, attos: u64) -> bool {
+        self.style.is_exact_sub_nanos(attos)
+    }
+}
+
+impl<S: DurationStyle> Duration<S> {
+    /// Whether the formatted output shows the duration without losing any precision.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use folktime::Folktime;
+    ///
+    /// assert!(Folktime::duration(Duration::from_secs(90)).is_exact());
+    /// assert!(!Folktime::duration(Duration::from_secs(124)).is_exact());
+    /// ```
+    pub fn is_exact(&self) -> bool {
+        self.1.is_exact(self.0)
+    }
+
+    /// Mark inexact output with `~`, see [Approx].
+    pub fn approx(self) -> Duration<Approx<S>> {
+        Duration(self.0, Approx::new(self.1))
+    }
+}
+
+/// Qualifier used by [Phrase] for values between two whole units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Qualifier {
+    /// Less than a quarter of a unit over the value, e.g. "about 2 minutes".
+    About,
+    /// Between a quarter and three quarters of a unit over the value, e.g. "over 2 minutes".
+    Over,
+    /// Less than a quarter of a unit under the value, e.g. "almost 3 minutes".
+    Almost,
+}
+
+impl Qualifier {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Qualifier::About => "about",
+            Qualifier::Over => "over",
+            Qualifier::Almost => "almost",
+        }
+    }
+}
+
+/// Format the duration in words in the largest possible unit, qualifying inexact values.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::Folktime;
+/// use folktime::duration::Phrase;
+///
+/// let a = Folktime::duration(Duration::from_secs(123)).with_style(Phrase);
+/// let b = Folktime::duration(Duration::from_secs(2 * 3600 + 50 * 60)).with_style(Phrase);
+/// let c = Folktime::duration(Duration::from_secs(30 * 3600)).with_style(Phrase);
+/// let d = Folktime::duration(Duration::from_secs(60)).with_style(Phrase);
+///
+/// assert_eq!(format!("{}", a), "about 2 minutes");
+/// assert_eq!(format!("{}", b), "almost 3 hours");
+/// assert_eq!(format!("{}", c), "over a day");
+/// assert_eq!(format!("{}", d), "a minute");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Phrase;
+
+impl Phrase {
+    /// Qualifier and whole value shown for `d`, `None` when the value is exact.
+    pub fn qualified(d: std::time::Duration) -> (Option<Qualifier>, Part) {
+        let unit = Unit::largest_in(d);
+        let (whole, rest) = unit.split(d);
+        let quarters = rest.as_nanos() * 4 / unit.as_nanos();
+        match quarters {
+            _ if rest.is_zero() => (None, Part::whole(whole, unit)),
+            0 => (Some(Qualifier::About), Part::whole(whole, unit)),
+            1 | 2 => (Some(Qualifier::Over), Part::whole(whole, unit)),
+            _ => {
+                let next = whole.saturating_add(1);
+                // Twelve months are a bit longer than a year, so also round up to the
+                // larger unit when the next value reaches past it.
+                let part = match unit.larger() {
+                    Some(larger) if next as u128 * unit.as_nanos() >= larger.as_nanos() => {
+                        Part::whole(1, larger)
+                    }
+                    _ => Part::whole(next, unit),
+                };
+                (Some(Qualifier::Almost), part)
+            }
+        }
+    }
+}
+
+impl DurationStyle for Phrase {
+    fn parts(&self, d: std::time::Duration) -> Parts {
+        Parts::one(Self::qualified(d).1)
+    }
+
+    fn fmt(&self, d: std::time::Duration, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (qualifier, part) = Self::qualified(d);
+        if let Some(qualifier) = qualifier {
+            write!(f, "{} ", qualifier.as_str())?;
+        }
+        if part.whole == 1 {
+            write!(f, "{} {}", part.unit.article(), part.unit.name())
+        } else {
+            write!(f, "{} {}", part.whole, part.unit.plural())
+        }
+    }
+
+    /// Below a nanosecond, the units of the parts are named 10^9 times smaller, e.g.
+    /// "about 312 picoseconds".
+    fn fmt_sub_nanos(&self, attos: u64, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (qualifier, part) = Self::qualified(std::time::Duration::from_nanos(attos));
+        if let Some(qualifier) = qualifier {
+            write!(f, "{} ", qualifier.as_str())?;
+        }
+        let unit = WideUnit::scaled_down(part.unit);
+        if part.whole == 1 {
+            write!(f, "{} {}", unit.article(), unit.name())
+        } else {
+            write!(f, "{} {}", part.whole, unit.plural())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        duration::{Approx, Phrase, Style, DAY, HOUR, MIN, YEAR},
+        Folktime,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn exact() {
+        let d = Folktime::duration(Duration::new(1, 500_000_000));
+        assert!(d.is_exact());
+        let d = Folktime::duration(Duration::new(1, 505_000_000));
+        assert!(!d.is_exact());
+        let d = Folktime::duration(Duration::new(0, 0));
+        assert!(d.is_exact());
+        let d = Folktime::duration(Duration::new(u64::MAX, 999_999_999));
+        assert!(!d.is_exact());
+    }
+    #[test]
+    fn exact_two_units() {
+        let d = Folktime::duration(Duration::new(123, 0)).with_style(Style::TwoUnitsWhole);
+        assert!(d.is_exact());
+        let d = Folktime::duration(Duration::new(123, 1)).with_style(Style::TwoUnitsWhole);
+        assert!(!d.is_exact());
+    }
+    #[test]
+    fn exact_saturates() {
+        use crate::duration::{Part, Parts, Unit};
+        let parts = Parts::two(
+            Part::frac(u64::MAX, 99, 2, Unit::GigaYear),
+            Part::whole(u64::MAX, Unit::GigaYear),
+        );
+        assert!(!parts.is_exact(Duration::MAX));
+        let parts = Parts::one(Part::frac(1, 5, 39, Unit::Second));
+        assert!(!parts.is_exact(Duration::from_secs(1)));
+        let parts = Parts::one(Part::frac(u64::MAX, 0, 12, Unit::Second));
+        assert!(!parts.is_exact(Duration::MAX));
+        let d = Folktime::duration(Duration::MAX).with_style(Approx::new(Style::OneUnitWhole));
+        assert_eq!(format!("{}", d), "~584Gy");
+    }
+    #[test]
+    fn approx_marker() {
+        let d = Folktime::duration(Duration::new(124, 0)).approx();
+        assert_eq!(format!("{}", d), "~2.06m");
+        let d = Folktime::duration(Duration::new(5, 0)).approx();
+        assert_eq!(format!("{}", d), "5.00s");
+        let d = Folktime::duration(Duration::new(HOUR + 1, 0))
+            .with_style(Approx::new(Style::TwoUnitsWhole).with_marker("≈"));
+        assert_eq!(format!("{}", d), "≈1h 0m");
+    }
+
+    #[test]
+    fn phrase_exact() {
+        let d = Folktime::duration(Duration::new(0, 0)).with_style(Phrase);
+        assert_eq!(format!("{}", d), "0 seconds");
+        let d = Folktime::duration(Duration::new(HOUR, 0)).with_style(Phrase);
+        assert_eq!(format!("{}", d), "an hour");
+        let d = Folktime::duration(Duration::new(0, 5)).with_style(Phrase);
+        assert_eq!(format!("{}", d), "5 nanoseconds");
+    }
+    #[test]
+    fn phrase_about() {
+        let d = Folktime::duration(Duration::new(2 * MIN + 14, 0)).with_style(Phrase);
+        assert_eq!(format!("{}", d), "about 2 minutes");
+    }
+    #[test]
+    fn phrase_over() {
+        let d = Folktime::duration(Duration::new(2 * MIN + 15, 0)).with_style(Phrase);
+        assert_eq!(format!("{}", d), "over 2 minutes");
+        let d = Folktime::duration(Duration::new(2 * MIN + 44, 0)).with_style(Phrase);
+        assert_eq!(format!("{}", d), "over 2 minutes");
+    }
+    #[test]
+    fn phrase_almost() {
+        let d = Folktime::duration(Duration::new(2 * MIN + 45, 0)).with_style(Phrase);
+        assert_eq!(format!("{}", d), "almost 3 minutes");
+        let d = Folktime::duration(Duration::new(HOUR - 1, 0)).with_style(Phrase);
+        assert_eq!(format!("{}", d), "almost an hour");
+        let d = Folktime::duration(Duration::new(23 * HOUR + 50 * MIN, 0)).with_style(Phrase);
+        assert_eq!(format!("{}", d), "almost a day");
+        let d = Folktime::duration(Duration::new(YEAR - DAY, 0)).with_style(Phrase);
+        assert_eq!(format!("{}", d), "almost a year");
+    }
+}
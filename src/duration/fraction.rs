@@ -0,0 +1,394 @@
+use super::{from_nanos, one_unit_frac::OneUnitFrac, DurationStyle, Part, Parts, Unit};
+
+/// How [Fraction] writes the snapped value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FractionForm {
+    #[default]
+    /// In words, e.g. "an hour and a half".
+    Words,
+    /// With Unicode vulgar fractions, e.g. `1½h`.
+    Vulgar,
+}
+
+/// Snap the duration to halves, quarters or thirds of a unit, e.g. "half an hour" or `2½d`.
+///
+/// Durations that are not within the tolerance of any such fraction are formatted
+/// like [Style::OneUnitFrac](super::Style::OneUnitFrac).
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::Folktime;
+/// use folktime::duration::Fraction;
+///
+/// let a = Folktime::duration(Duration::from_secs(90 * 60)).with_style(Fraction::words());
+/// let b = Folktime::duration(Duration::from_secs(15 * 60)).with_style(Fraction::words());
+/// let c = Folktime::duration(Duration::from_secs(60 * 60 * 60)).with_style(Fraction::vulgar());
+/// let d = Folktime::duration(Duration::from_secs(123)).with_style(Fraction::words());
+///
+/// assert_eq!(format!("{}", a), "an hour and a half");
+/// assert_eq!(format!("{}", b), "a quarter of an hour");
+/// assert_eq!(format!("{}", c), "2½d");
+/// assert_eq!(format!("{}", d), "2.05m");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fraction {
+    form: FractionForm,
+    tolerance: u32,
+    quarters: bool,
+    thirds: bool,
+}
+
+impl Default for Fraction {
+    fn default() -> Self {
+        Self::words()
+    }
+}
+
+/// A value snapped to `whole + num / den` units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Snapped {
+    whole: u64,
+    num: u8,
+    den: u8,
+    unit: Unit,
+}
+
+impl Snapped {
+    /// The snapped value in nanoseconds, saturating.
+    fn as_nanos(&self) -> u128 {
+        let unit = self.unit.as_nanos();
+        (self.whole as u128)
+            .saturating_mul(unit)
+            .saturating_add(self.num as u128 * unit / self.den as u128)
+    }
+
+    /// Whether the snapped value is exactly `d`, compared in units of `1 / den`.
+    fn is(&self, d: std::time::Duration) -> bool {
+        let (unit, den) = (self.unit.as_nanos(), self.den as u128);
+        let snapped = (self.whole as u128)
+            .checked_mul(unit * den)
+            .and_then(|whole| whole.checked_add(self.num as u128 * unit));
+        snapped == Some(d.as_nanos() * den)
+    }
+}
+
+impl Fraction {
+    /// Write snapped values in words.
+    pub const fn words() -> Self {
+        Self {
+            form: FractionForm::Words,
+            tolerance: 5,
+            quarters: true,
+            thirds: true,
+        }
+    }
+
+    /// Write snapped values with vulgar fractions.
+    pub const fn vulgar() -> Self {
+        Self {
+            form: FractionForm::Vulgar,
+            ..Self::words()
+        }
+    }
+
+    /// Distance from a fraction, in percent of the unit the value is in, below which values
+    /// snap to it. Defaults to 5%.
+    ///
+    /// The tolerance is in the value's own unit also when snapping to a fraction of the
+    /// next larger unit, so e.g. with 5% only values within 3 minutes of 12 hours are
+    /// "half a day".
+    pub const fn with_tolerance(self, percent: u32) -> Self {
+        Self {
+            tolerance: percent,
+            ..self
+        }
+    }
+
+    /// Whether to snap to quarters.
+    pub const fn with_quarters(self, quarters: bool) -> Self {
+        Self { quarters, ..self }
+    }
+
+    /// Whether to snap to thirds.
+    pub const fn with_thirds(self, thirds: bool) -> Self {
+        Self { thirds, ..self }
+    }
+
+    fn fractions(&self) -> impl Iterator<Item = (u8, u8)> {
+        let quarters = self.quarters.then_some([(1, 4), (3, 4)]);
+        let thirds = self.thirds.then_some([(1, 3), (2, 3)]);
+        [(0, 1), (1, 2), (1, 1)]
+            .into_iter()
+            .chain(quarters.into_iter().flatten())
+            .chain(thirds.into_iter().flatten())
+    }
+
+    /// Snap `d` in `unit` to the closest fraction within the tolerance, measured in `own`.
+    /// Also returns the distance from the snapped value in nanoseconds.
+    fn snap_in(&self, d: std::time::Duration, unit: Unit, own: Unit) -> Option<(Snapped, u128)> {
+        let (whole, rest) = unit.split(d);
+        let rest = rest.as_nanos();
+        let unit_nanos = unit.as_nanos();
+        let tolerance = self.tolerance as u128 * own.as_nanos();
+        let (snapped, error) = self
+            .fractions()
+            .map(|(num, den)| {
+                let error = (rest * den as u128).abs_diff(num as u128 * unit_nanos);
+                let snapped = match (num, den) {
+                    (1, 1) => Snapped {
+                        whole: whole.saturating_add(1),
+                        num: 0,
+                        den: 1,
+                        unit,
+                    },
+                    _ => Snapped {
+                        whole,
+                        num,
+                        den,
+                        unit,
+                    },
+                };
+                (snapped, error)
+            })
+            .filter(|(s, error)| error * 100 < tolerance * s.den as u128)
+            .filter(|(s, _)| {
+                // Just past the tolerance, the value is shown like `OneUnitFrac`, which
+                // truncates, e.g. to `16.3h` after 16⅓ hours. Don't snap where that goes back.
+                let value = s.as_nanos();
+                let past = value.saturating_add(tolerance / 100);
+                let past = from_nanos(past.min(std::time::Duration::MAX.as_nanos()));
+                OneUnitFrac.parts(past).first().as_nanos() >= value
+            })
+            .min_by(|(a, a_error), (b, b_error)| {
+                (a_error * b.den as u128).cmp(&(b_error * a.den as u128))
+            })?;
+        Some((snapped, error / snapped.den as u128))
+    }
+
+    /// Snap `d` in the largest unit that fits into it, or as a fraction of the next larger unit.
+    ///
+    /// The closer of the two wins. When both are equally close, halves and fractions of an
+    /// hour are preferred ("half a day", "a quarter of an hour" over "12 hours", "15 minutes").
+    fn snap(&self, d: std::time::Duration) -> Option<Snapped> {
+        if d.is_zero() {
+            return None;
+        }
+        let unit = Unit::largest_in(d);
+        let own = self.snap_in(d, unit, unit);
+        let larger = unit
+            .larger()
+            .and_then(|larger| self.snap_in(d, larger, unit))
+            .filter(|(s, _)| s.whole > 0 || s.num > 0);
+        match (larger, own) {
+            (Some((larger, a)), Some((own, b))) => {
+                let idiomatic = larger.den == 2 || larger.unit == Unit::Hour;
+                Some(if a < b || (a == b && idiomatic) {
+                    larger
+                } else {
+                    own
+                })
+            }
+            (larger, own) => larger.or(own).map(|(s, _)| s),
+        }
+    }
+}
+
+fn fraction_words(num: u8, den: u8) -> &'static str {
+    match (num, den) {
+        (1, 2) => "a half",
+        (1, 4) => "a quarter",
+        (3, 4) => "three quarters",
+        (1, 3) => "a third",
+        (2, 3) => "two thirds",
+        _ => unreachable!(),
+    }
+}
+
+fn fraction_vulgar(num: u8, den: u8) -> char {
+    match (num, den) {
+        (1, 2) => '½',
+        (1, 4) => '¼',
+        (3, 4) => '¾',
+        (1, 3) => '⅓',
+        (2, 3) => '⅔',
+        _ => unreachable!(),
+    }
+}
+
+impl DurationStyle for Fraction {
+    fn parts(&self, d: std::time::Duration) -> Parts {
+        match self.snap(d) {
+            Some(s) if s.num == 0 => Parts::one(Part::whole(s.whole, s.unit)),
+            Some(s) => {
+                let frac = s.num as u32 * 100 / s.den as u32;
+                Parts::one(Part::frac(s.whole, frac, 2, s.unit))
+            }
+            None => OneUnitFrac.parts(d),
+        }
+    }
+
+    fn fmt(&self, d: std::time::Duration, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let Some(s) = self.snap(d) else {
+            return OneUnitFrac.fmt(d, f);
+        };
+        let unit = s.unit;
+        match self.form {
+            FractionForm::Vulgar => match (s.whole, s.num) {
+                (whole, 0) => write!(f, "{whole}{unit}"),
+                (0, num) => write!(f, "{}{unit}", fraction_vulgar(num, s.den)),
+                (whole, num) => write!(f, "{whole}{}{unit}", fraction_vulgar(num, s.den)),
+            },
+            FractionForm::Words => match (s.whole, s.num) {
+                (1, 0) => write!(f, "{} {}", unit.article(), unit.name()),
+                (whole, 0) => write!(f, "{whole} {}", unit.plural()),
+                (0, 1) if s.den == 2 => write!(f, "half {} {}", unit.article(), unit.name()),
+                (0, num) => write!(
+                    f,
+                    "{} of {} {}",
+                    fraction_words(num, s.den),
+                    unit.article(),
+                    unit.name()
+                ),
+                (1, num) => write!(
+                    f,
+                    "{} {} and {}",
+                    unit.article(),
+                    unit.name(),
+                    fraction_words(num, s.den)
+                ),
+                (whole, 1) if s.den == 2 => write!(f, "{whole} and a half {}", unit.plural()),
+                (whole, num) => write!(
+                    f,
+                    "{whole} {} and {}",
+                    unit.plural(),
+                    fraction_words(num, s.den)
+                ),
+            },
+        }
+    }
+
+    /// Snapped values are exact only when the fraction is, whatever the parts say.
+    fn is_exact(&self, d: std::time::Duration) -> bool {
+        match self.snap(d) {
+            Some(s) => s.is(d),
+            None => OneUnitFrac.is_exact(d),
+        }
+    }
+
+    /// Values below a nanosecond are not snapped.
+    fn fmt_sub_nanos(&self, attos: u64, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        OneUnitFrac.fmt_sub_nanos(attos, f)
+    }
+
+    fn is_exact_sub_nanos(&self, attos: u64) -> bool {
+        OneUnitFrac.is_exact_sub_nanos(attos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        duration::{one_unit_frac::OneUnitFrac, DurationStyle, Fraction, DAY, HOUR, MIN},
+        Folktime,
+    };
+    use std::time::Duration;
+
+    fn words(secs: u64) -> String {
+        let d = Folktime::duration(Duration::from_secs(secs)).with_style(Fraction::words());
+        format!("{}", d)
+    }
+    fn vulgar(secs: u64) -> String {
+        let d = Folktime::duration(Duration::from_secs(secs)).with_style(Fraction::vulgar());
+        format!("{}", d)
+    }
+
+    #[test]
+    fn words_below_one() {
+        assert_eq!(words(30 * MIN), "half an hour");
+        assert_eq!(words(15 * MIN), "a quarter of an hour");
+        assert_eq!(words(45 * MIN), "three quarters of an hour");
+        assert_eq!(words(20 * MIN), "a third of an hour");
+        assert_eq!(words(12 * HOUR), "half a day");
+        assert_eq!(words(16 * HOUR), "16 hours");
+        assert_eq!(words(16 * HOUR + 10 * MIN), "16.1h");
+        assert_eq!(words(11 * HOUR + 5 * MIN), "11.0h");
+        assert_eq!(words(12 * HOUR + 2 * MIN), "half a day");
+    }
+    #[test]
+    fn words_above_one() {
+        assert_eq!(words(90 * MIN), "an hour and a half");
+        assert_eq!(words(HOUR + 15 * MIN), "an hour and a quarter");
+        assert_eq!(words(2 * DAY + 12 * HOUR), "2 and a half days");
+        assert_eq!(words(3 * HOUR + 40 * MIN), "3 hours and two thirds");
+        assert_eq!(words(2 * DAY + 6 * HOUR), "2 days and a quarter");
+    }
+    #[test]
+    fn words_whole() {
+        assert_eq!(words(HOUR), "an hour");
+        assert_eq!(words(3 * HOUR), "3 hours");
+        assert_eq!(words(3 * HOUR + MIN), "3 hours");
+    }
+    #[test]
+    fn vulgar_forms() {
+        assert_eq!(vulgar(30 * MIN), "½h");
+        assert_eq!(vulgar(2 * DAY + 12 * HOUR), "2½d");
+        assert_eq!(vulgar(HOUR + 20 * MIN), "1⅓h");
+        assert_eq!(vulgar(5 * MIN), "5m");
+    }
+    #[test]
+    fn tolerance() {
+        assert_eq!(words(HOUR + 31 * MIN), "an hour and a half");
+        let d = Folktime::duration(Duration::from_secs(HOUR + 31 * MIN))
+            .with_style(Fraction::words().with_tolerance(1));
+        assert_eq!(format!("{}", d), "1.51h");
+    }
+    #[test]
+    fn denominators() {
+        let d = Folktime::duration(Duration::from_secs(20 * MIN))
+            .with_style(Fraction::words().with_thirds(false));
+        assert_eq!(format!("{}", d), "20 minutes");
+        let d = Folktime::duration(Duration::from_secs(HOUR + 15 * MIN))
+            .with_style(Fraction::words().with_quarters(false));
+        assert_eq!(format!("{}", d), "1.25h");
+    }
+    #[test]
+    fn exact() {
+        let approx = |secs: u64, style: Fraction| {
+            let d = Folktime::duration(Duration::from_secs(secs)).with_style(style);
+            format!("{}", d.approx())
+        };
+        assert_eq!(approx(20 * MIN, Fraction::words()), "a third of an hour");
+        assert_eq!(approx(40 * MIN, Fraction::vulgar()), "⅔h");
+        assert_eq!(approx(HOUR + 30 * MIN, Fraction::vulgar()), "1½h");
+        assert_eq!(approx(HOUR + 31 * MIN, Fraction::vulgar()), "~1½h");
+        assert_eq!(approx(3 * HOUR + MIN, Fraction::words()), "~3 hours");
+        assert_eq!(approx(123, Fraction::words()), "2.05m");
+        assert_eq!(approx(124, Fraction::words()), "~2.06m");
+    }
+    #[test]
+    fn no_backwards_snap() {
+        assert_eq!(words(16 * HOUR + 20 * MIN), "16.3h");
+        assert_eq!(words(16 * HOUR + 15 * MIN), "16 hours and a quarter");
+    }
+    #[test]
+    fn monotone() {
+        for style in [Fraction::words(), Fraction::words().with_tolerance(20)] {
+            let value = |d| match style.snap(d) {
+                Some(s) => s.as_nanos(),
+                None => OneUnitFrac.parts(d).first().as_nanos(),
+            };
+            let mut last = 0;
+            for secs in 0..3 * DAY {
+                let v = value(Duration::from_secs(secs));
+                assert!(v >= last, "{secs}s goes back");
+                last = v;
+            }
+        }
+    }
+    #[test]
+    fn fallback() {
+        assert_eq!(words(0), "0.00s");
+        assert_eq!(words(123), "2.05m");
+    }
+}
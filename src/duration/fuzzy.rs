@@ -0,0 +1,243 @@
+use super::{DurationStyle, Part, Parts, Unit};
+
+/// Thresholds at which [Fuzzy] switches to the next larger unit.
+///
+/// Each threshold is the rounded count of a unit from which the next larger unit is
+/// used instead. The defaults match the ones used by Moment.js.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Thresholds {
+    /// Seconds below which the duration is "a few seconds".
+    pub seconds: u64,
+    /// Minutes from which hours are used.
+    pub minutes: u64,
+    /// Hours from which days are used.
+    pub hours: u64,
+    /// Days from which months are used.
+    pub days: u64,
+    /// Months from which years are used.
+    pub months: u64,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            seconds: 45,
+            minutes: 45,
+            hours: 22,
+            days: 26,
+            months: 11,
+        }
+    }
+}
+
+/// Format the duration in words with rounded values and fuzzy unit boundaries,
+/// e.g. "a few seconds", "44 minutes" or "an hour".
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::Folktime;
+/// use folktime::duration::Fuzzy;
+///
+/// let a = Folktime::duration(Duration::from_secs(30)).with_style(Fuzzy::new());
+/// let b = Folktime::duration(Duration::from_secs(44 * 60)).with_style(Fuzzy::new());
+/// let c = Folktime::duration(Duration::from_secs(50 * 60)).with_style(Fuzzy::new());
+/// let d = Folktime::duration(Duration::from_secs(50 * 60)).with_style(Fuzzy::new().with_articles(false));
+///
+/// assert_eq!(format!("{}", a), "a few seconds");
+/// assert_eq!(format!("{}", b), "44 minutes");
+/// assert_eq!(format!("{}", c), "an hour");
+/// assert_eq!(format!("{}", d), "1 hour");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fuzzy {
+    thresholds: Thresholds,
+    articles: bool,
+}
+
+impl Default for Fuzzy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fuzzy {
+    /// Fuzzy style with the default [Thresholds].
+    pub fn new() -> Self {
+        Self {
+            thresholds: Thresholds::default(),
+            articles: true,
+        }
+    }
+
+    /// Set the thresholds at which larger units are used.
+    pub fn with_thresholds(self, thresholds: Thresholds) -> Self {
+        Self { thresholds, ..self }
+    }
+
+    /// Whether exactly one unit is written with an article ("an hour") or a number ("1 hour").
+    pub fn with_articles(self, articles: bool) -> Self {
+        Self { articles, ..self }
+    }
+
+    /// Whether the duration is short enough to be "a few seconds".
+    fn is_few_seconds(&self, d: std::time::Duration) -> bool {
+        rounded(d, Unit::Second) < self.thresholds.seconds
+    }
+}
+
+/// Number of `unit`s in `d`, rounded half up.
+fn rounded(d: std::time::Duration, unit: Unit) -> u64 {
+    let unit = unit.as_nanos();
+    u64::try_from((d.as_nanos() + unit / 2) / unit).unwrap_or(u64::MAX)
+}
+
+impl DurationStyle for Fuzzy {
+    fn parts(&self, d: std::time::Duration) -> Parts {
+        let t = &self.thresholds;
+        let ladder = [
+            (Unit::Second, t.seconds),
+            (Unit::Minute, t.minutes),
+            (Unit::Hour, t.hours),
+            (Unit::Day, t.days),
+            (Unit::Month, t.months),
+        ];
+        // A unit is used while its rounded count stays below the threshold, unless the
+        // next unit's rounded count is still zero.
+        for (i, (unit, threshold)) in ladder.into_iter().enumerate() {
+            let count = rounded(d, unit);
+            let next = ladder.get(i + 1).map_or(Unit::Year, |(unit, _)| *unit);
+            if count < threshold || rounded(d, next) == 0 {
+                return Parts::one(Part::whole(count, unit));
+            }
+        }
+        Parts::one(Part::whole(rounded(d, Unit::Year).max(1), Unit::Year))
+    }
+
+    fn fmt(&self, d: std::time::Duration, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_few_seconds(d) {
+            return f.write_str("a few seconds");
+        }
+        let part = self.parts(d).first();
+        match part.whole {
+            1 if self.articles => write!(f, "{} {}", part.unit.article(), part.unit.name()),
+            1 => write!(f, "1 {}", part.unit.name()),
+            whole => write!(f, "{} {}", whole, part.unit.plural()),
+        }
+    }
+
+    /// "A few seconds" is never exact, whatever the parts say.
+    fn is_exact(&self, d: std::time::Duration) -> bool {
+        !self.is_few_seconds(d) && self.parts(d).is_exact(d)
+    }
+
+    /// Below a nanosecond is written like zero.
+    fn fmt_sub_nanos(&self, _: u64, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.fmt(std::time::Duration::ZERO, f)
+    }
+
+    fn is_exact_sub_nanos(&self, _: u64) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        duration::{Approx, Fuzzy, Thresholds, DAY, HOUR, MIN, MONTH, YEAR},
+        Folktime,
+    };
+    use std::time::Duration;
+
+    fn fuzzy(secs: u64) -> String {
+        format!(
+            "{}",
+            Folktime::duration(Duration::from_secs(secs)).with_style(Fuzzy::new())
+        )
+    }
+
+    #[test]
+    fn seconds() {
+        assert_eq!(fuzzy(0), "a few seconds");
+        assert_eq!(fuzzy(44), "a few seconds");
+        assert_eq!(fuzzy(45), "a minute");
+        assert_eq!(fuzzy(89), "a minute");
+    }
+    #[test]
+    fn minutes() {
+        assert_eq!(fuzzy(90), "2 minutes");
+        assert_eq!(fuzzy(44 * MIN), "44 minutes");
+        assert_eq!(fuzzy(44 * MIN + 29), "44 minutes");
+        assert_eq!(fuzzy(44 * MIN + 30), "an hour");
+    }
+    #[test]
+    fn hours() {
+        assert_eq!(fuzzy(89 * MIN), "an hour");
+        assert_eq!(fuzzy(90 * MIN), "2 hours");
+        assert_eq!(fuzzy(21 * HOUR), "21 hours");
+        assert_eq!(fuzzy(22 * HOUR), "a day");
+    }
+    #[test]
+    fn days() {
+        assert_eq!(fuzzy(35 * HOUR), "a day");
+        assert_eq!(fuzzy(36 * HOUR), "2 days");
+        assert_eq!(fuzzy(25 * DAY), "25 days");
+        assert_eq!(fuzzy(26 * DAY), "a month");
+    }
+    #[test]
+    fn months() {
+        assert_eq!(fuzzy(2 * MONTH), "2 months");
+        assert_eq!(fuzzy(10 * MONTH), "10 months");
+        assert_eq!(fuzzy(11 * MONTH), "a year");
+    }
+    #[test]
+    fn years() {
+        assert_eq!(fuzzy(YEAR), "a year");
+        assert_eq!(fuzzy(YEAR + YEAR / 2), "2 years");
+        assert_eq!(fuzzy(100 * YEAR), "100 years");
+    }
+    #[test]
+    fn thresholds() {
+        let style = Fuzzy::new().with_thresholds(Thresholds {
+            seconds: 10,
+            minutes: 60,
+            ..Default::default()
+        });
+        let d = Folktime::duration(Duration::from_secs(20)).with_style(style);
+        assert_eq!(format!("{}", d), "20 seconds");
+        let d = Folktime::duration(Duration::from_secs(59 * MIN)).with_style(style);
+        assert_eq!(format!("{}", d), "59 minutes");
+    }
+    #[test]
+    fn zero() {
+        let style = Fuzzy::new().with_thresholds(Thresholds {
+            seconds: 0,
+            ..Default::default()
+        });
+        let fmt = |d| format!("{}", Folktime::duration(d).with_style(style));
+        assert_eq!(fmt(Duration::ZERO), "0 seconds");
+        assert_eq!(fmt(Duration::from_millis(400)), "0 seconds");
+        assert_eq!(fmt(Duration::from_millis(500)), "a second");
+        assert_eq!(fmt(Duration::from_secs(2)), "2 seconds");
+    }
+    #[test]
+    fn no_articles() {
+        let style = Fuzzy::new().with_articles(false);
+        let d = Folktime::duration(Duration::from_secs(DAY)).with_style(style);
+        assert_eq!(format!("{}", d), "1 day");
+    }
+    #[test]
+    fn approx() {
+        let fmt = |secs| {
+            let d = Folktime::duration(Duration::from_secs(secs));
+            format!("{}", d.with_style(Approx::new(Fuzzy::new())))
+        };
+        assert_eq!(fmt(0), "~a few seconds");
+        assert_eq!(fmt(30), "~a few seconds");
+        assert_eq!(fmt(44 * MIN), "44 minutes");
+        assert_eq!(fmt(50 * MIN), "~an hour");
+        assert!(!Folktime::duration(Duration::from_secs(30))
+            .with_style(Fuzzy::new())
+            .is_exact());
+    }
+}
@@ -1,6 +1,27 @@
+mod approx;
+mod fraction;
+mod fuzzy;
 pub mod one_unit_frac;
 pub mod one_unit_whole;
+mod parse;
+mod parts;
+mod refresh;
+mod sentinel;
 pub mod two_units_whole;
+mod uncertain;
+mod wide;
+
+pub use approx::{Approx, Phrase, Qualifier};
+pub use fraction::{Fraction, FractionForm};
+pub use fuzzy::{Fuzzy, Thresholds};
+pub use parse::{parse, ParseError};
+pub use parts::{Part, Parts, Unit};
+pub use sentinel::{Forever, Optional};
+pub use uncertain::Uncertain;
+pub use wide::{Wide, WideUnit};
+
+pub(crate) use parts::{fmt_number, fmt_quotient, from_nanos, NANOS_PER_SEC};
+pub(crate) use refresh::first_change;
 
 use std::fmt::Display;
 
@@ -17,7 +38,7 @@ const GIGA_YEAR: u64 = 1_000 * MEGA_YEAR;
 const US: u32 = 1_000;
 const MS: u32 = 1_000 * US;
 
-#[derive(Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 /// Formatting style for [std::time::Duration].
 pub enum Style {
     #[default]
@@ -57,13 +78,150 @@ pub enum Style {
     TwoUnitsWhole,
 }
 
-pub struct Duration(pub std::time::Duration, pub Style);
+/// A way of formatting [std::time::Duration].
+///
+/// Implement this trait to define a custom style. A style chooses the [Parts] to show
+/// and may override [DurationStyle::fmt] to render them differently. The [Unit] ladder
+/// helpers and the built-in styles in [one_unit_frac], [one_unit_whole] and
+/// [two_units_whole] can be used as building blocks.
+///
+/// # Example
+/// ```
+/// use std::fmt;
+/// use std::time::Duration;
+/// use folktime::Folktime;
+/// use folktime::duration::{DurationStyle, Part, Parts, Unit};
+///
+/// /// Two units with zero-padded minor part, e.g. `1h02m`.
+/// struct Clock;
+///
+/// impl DurationStyle for Clock {
+///     fn parts(&self, d: Duration) -> Parts {
+///         let unit = Unit::largest_in(d);
+///         let (big, rest) = unit.split(d);
+///         match unit.smaller() {
+///             Some(small) => Parts::two(
+///                 Part::whole(big, unit),
+///                 Part::whole(small.split(rest).0, small),
+///             ),
+///             None => Parts::one(Part::whole(big, unit)),
+///         }
+///     }
+///
+///     fn fmt(&self, d: Duration, f: &mut fmt::Formatter) -> fmt::Result {
+///         let parts = self.parts(d);
+///         match parts.second() {
+///             Some(p) => write!(f, "{}{:02}{}", parts.first(), p.whole, p.unit),
+///             None => write!(f, "{}", parts.first()),
+///         }
+///     }
+/// }
+///
+/// let d = Folktime::duration(Duration::from_secs(3720)).with_style(Clock);
+/// assert_eq!(format!("{}", d), "1h02m");
+/// ```
+pub trait DurationStyle {
+    /// Values and units to show for `d`.
+    fn parts(&self, d: std::time::Duration) -> Parts;
+
+    /// Format `d`. Defaults to formatting [DurationStyle::parts].
+    fn fmt(&self, d: std::time::Duration, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.parts(d))
+    }
+
+    /// Whether the output shows `d` without losing any precision. Defaults to
+    /// [Parts::is_exact].
+    ///
+    /// Styles whose [DurationStyle::fmt] writes something other than the parts, such as
+    /// words, override this.
+    fn is_exact(&self, d: std::time::Duration) -> bool {
+        self.parts(d).is_exact(d)
+    }
+
+    /// Format a duration of less than a nanosecond, given in attoseconds, as used by
+    /// [Wide] and [Ticks](crate::ticks::Ticks). Defaults to writing the
+    /// [DurationStyle::parts] of as many nanoseconds in units 10^9 times smaller, so that
+    /// e.g. `312ms` is written as `312ps`.
+    ///
+    /// Styles whose [DurationStyle::fmt] writes something other than the parts override
+    /// this together with [DurationStyle::is_exact_sub_nanos].
+    fn fmt_sub_nanos(&self, attos: u64, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        wide::fmt_scaled(self.parts(std::time::Duration::from_nanos(attos)), f)
+    }
+
+    /// Whether [DurationStyle::fmt_sub_nanos] shows `attos` attoseconds without losing any
+    /// precision. Defaults to [Parts::is_exact] of as many nanoseconds.
+    fn is_exact_sub_nanos(&self, attos: u64) -> bool {
+        let d = std::time::Duration::from_nanos(attos);
+        self.parts(d).is_exact(d)
+    }
+}
+
+impl DurationStyle for Style {
+    fn parts(&self, d: std::time::Duration) -> Parts {
+        match self {
+            Style::OneUnitFrac => one_unit_frac::OneUnitFrac.parts(d),
+            Style::OneUnitWhole => one_unit_whole::OneUnitWhole.parts(d),
+            Style::TwoUnitsWhole => two_units_whole::TwoUnitsWhole.parts(d),
+        }
+    }
+}
+
+impl<T: DurationStyle + ?Sized> DurationStyle for &T {
+    fn parts(&self, d: std::time::Duration) -> Parts {
+        (**self).parts(d)
+    }
+
+    fn fmt(&self, d: std::time::Duration, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        (**self).fmt(d, f)
+    }
+
+    fn is_exact(&self, d: std::time::Duration) -> bool {
+        (**self).is_exact(d)
+    }
+
+    fn fmt_sub_nanos(&self, attos: u64, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        (**self).fmt_sub_nanos(attos, f)
+    }
+
+    fn is_exact_sub_nanos(&self, attos: u64) -> bool {
+        (**self).is_exact_sub_nanos(attos)
+    }
+}
+
+impl<T: DurationStyle + ?Sized> DurationStyle for Box<T> {
+    fn parts(&self, d: std::time::Duration) -> Parts {
+        (**self).parts(d)
+    }
+
+    fn fmt(&self, d: std::time::Duration, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        (**self).fmt(d, f)
+    }
+
+    fn is_exact(&self, d: std::time::Duration) -> bool {
+        (**self).is_exact(d)
+    }
+
+    fn fmt_sub_nanos(&self, attos: u64, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        (**self).fmt_sub_nanos(attos, f)
+    }
+
+    fn is_exact_sub_nanos(&self, attos: u64) -> bool {
+        (**self).is_exact_sub_nanos(attos)
+    }
+}
+
+/// A [std::time::Duration] with a formatting style, either a built-in [Style] or any
+/// [DurationStyle].
+pub struct Duration<S = Style>(pub std::time::Duration, pub S);
 
 impl Duration {
     pub fn new(d: std::time::Duration) -> Self {
         Self(d, Default::default())
     }
+}
 
+impl<S> Duration<S> {
     /// Set the formatting style.
     ///
     /// # Example
@@ -75,17 +233,78 @@ impl Duration {
     /// let d = Folktime::duration(Duration::from_secs(123)).with_style(Style::TwoUnitsWhole);
     /// assert_eq!(format!("{}", d), "2m 3s");
     /// ```
-    pub fn with_style(self, units: Style) -> Self {
-        Self(self.0, units)
+    pub fn with_style<T: DurationStyle>(self, units: T) -> Duration<T> {
+        Duration(self.0, units)
+    }
+}
+
+impl<S: DurationStyle> Duration<S> {
+    /// Values and units chosen by the formatting style, without formatting them.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use folktime::Folktime;
+    /// use folktime::duration::{Style, Unit};
+    ///
+    /// let d = Folktime::duration(Duration::new(12, 345_000_000)).with_style(Style::TwoUnitsWhole);
+    /// let parts: Vec<_> = d.parts().iter().map(|p| (p.whole, p.unit)).collect();
+    /// assert_eq!(parts, [(12, Unit::Second), (345, Unit::Millisecond)]);
+    /// ```
+    pub fn parts(&self) -> Parts {
+        self.1.parts(self.0)
     }
 }
 
-impl Display for Duration {
+/// Formatting honors width, alignment and fill, so the output can be kept at a fixed width.
+/// Precision is left to the style.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::Folktime;
+///
+/// let d = Folktime::duration(Duration::from_secs(5));
+/// assert_eq!(format!("[{:>6}]", d), "[ 5.00s]");
+/// assert_eq!(format!("[{:<6}]", d), "[5.00s ]");
+/// assert_eq!(format!("[{:_^7.1}]", d), "[_5.00s_]");
+/// ```
+impl<S: DurationStyle> Display for Duration<S> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self.1 {
-            Style::OneUnitFrac => self.fmt_one_unit_frac(f),
-            Style::OneUnitWhole => self.fmt_one_unit_whole(f),
-            Style::TwoUnitsWhole => self.fmt_two_units_whole(f),
+        if f.width().is_some() {
+            pad(&Unpadded(self).to_string(), f)
+        } else {
+            self.1.fmt(self.0, f)
         }
     }
 }
+
+/// Write `s` padded to the width of `f`, with its alignment and fill.
+///
+/// Unlike [std::fmt::Formatter::pad], this does not truncate `s` to the precision.
+pub(crate) fn pad(s: &str, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let padding = f.width().unwrap_or(0).saturating_sub(s.chars().count());
+    let (before, after) = match f.align() {
+        Some(std::fmt::Alignment::Right) => (padding, 0),
+        Some(std::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        write!(f, "{fill}")?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        write!(f, "{fill}")?;
+    }
+    Ok(())
+}
+
+/// Formats a [Duration] ignoring width and alignment, so it can be padded as a whole.
+struct Unpadded<'a, S>(&'a Duration<S>);
+
+impl<S: DurationStyle> Display for Unpadded<'_, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0 .1.fmt(self.0 .0, f)
+    }
+}
@@ -1,19 +1,17 @@
-use super::{Duration, DAY, GIGA_YEAR, HOUR, KILO_YEAR, MEGA_YEAR, MIN, MONTH, MS, US, WEEK, YEAR};
+use super::{
+    Duration, DurationStyle, Part, Parts, Unit, DAY, GIGA_YEAR, HOUR, KILO_YEAR, MEGA_YEAR, MIN,
+    MONTH, MS, US, WEEK, YEAR,
+};
 
 macro_rules! fmt_three {
     ($big:ty, $small:ty, $i:ident) => {
-        fn $i(
-            big: $big,
-            small: $small,
-            unit: &str,
-            f: &mut std::fmt::Formatter,
-        ) -> std::fmt::Result {
+        fn $i(big: $big, small: $small, unit: Unit) -> Part {
             if big < 10 {
-                write!(f, "{}.{:02}{unit}", big, small / 10)
+                Part::frac(big as u64, small / 10, 2, unit)
             } else if big < 100 {
-                write!(f, "{}.{:01}{unit}", big, small / 100)
+                Part::frac(big as u64, small / 100, 1, unit)
             } else {
-                write!(f, "{}{unit}", big)
+                Part::whole(big as u64, unit)
             }
         }
     };
@@ -22,72 +20,83 @@ macro_rules! fmt_three {
 fmt_three!(u32, u32, fmt_three_u32);
 fmt_three!(u64, u32, fmt_three_u64_u32);
 
-fn fmt_100(val: u64, div: u64, unit: &str, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+fn fmt_100(val: u64, div: u64, unit: Unit) -> Part {
     let a = (val * 100) / div;
     let big = a / 100;
-    let small = a % 100;
+    let small = (a % 100) as u32;
     if big < 10 {
-        write!(f, "{}.{:02}{}", big, small, unit)
+        Part::frac(big, small, 2, unit)
     } else if big < 100 {
-        write!(f, "{}.{:01}{}", big, small / 10, unit)
+        Part::frac(big, small / 10, 1, unit)
     } else {
-        write!(f, "{}{}", big, unit)
+        Part::whole(big, unit)
     }
 }
 
-impl Duration {
-    pub fn fmt_one_unit_frac(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let secs = self.0.as_secs();
-        let ns = self.0.subsec_nanos();
+/// [Style::OneUnitFrac](super::Style::OneUnitFrac) as a [DurationStyle].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OneUnitFrac;
+
+impl DurationStyle for OneUnitFrac {
+    fn parts(&self, d: std::time::Duration) -> Parts {
+        let secs = d.as_secs();
+        let ns = d.subsec_nanos();
 
-        if secs < 1 {
+        let part = if secs < 1 {
             if ns < US {
                 if ns == 0 {
-                    write!(f, "0.00s")
+                    Part::frac(0, 0, 2, Unit::Second)
                 } else {
-                    write!(f, "{}ns", ns)
+                    Part::whole(ns as u64, Unit::Nanosecond)
                 }
             } else if ns < MS {
                 let us = ns / US;
                 let ns = ns % US;
-                fmt_three_u32(us, ns, "us", f)
+                fmt_three_u32(us, ns, Unit::Microsecond)
             } else {
                 let ms = ns / MS;
                 let us = (ns % MS) / US;
-                fmt_three_u32(ms, us, "ms", f)
+                fmt_three_u32(ms, us, Unit::Millisecond)
             }
         } else if secs < MIN {
             let ms = ns / 1_000_000;
-            fmt_three_u64_u32(secs, ms, "s", f)
+            fmt_three_u64_u32(secs, ms, Unit::Second)
         } else if secs < HOUR {
             if secs < 10 * MIN {
                 let hundredths = ns / 10_000_000;
                 let val = secs * 100 + hundredths as u64;
-                write!(f, "{}.{:02}m", val / 6000, (val / 60) % 100)
+                Part::frac(val / 6000, ((val / 60) % 100) as u32, 2, Unit::Minute)
             } else {
-                fmt_100(secs, MIN, "m", f)
+                fmt_100(secs, MIN, Unit::Minute)
             }
         } else if secs < DAY {
-            fmt_100(secs, HOUR, "h", f)
+            fmt_100(secs, HOUR, Unit::Hour)
         } else if secs < WEEK {
-            fmt_100(secs, DAY, "d", f)
+            fmt_100(secs, DAY, Unit::Day)
         } else if secs < MONTH {
-            fmt_100(secs, WEEK, "w", f)
+            fmt_100(secs, WEEK, Unit::Week)
         } else if secs < YEAR {
-            fmt_100(secs, MONTH, "mo", f)
+            fmt_100(secs, MONTH, Unit::Month)
         } else if secs < KILO_YEAR {
-            fmt_100(secs, YEAR, "y", f)
+            fmt_100(secs, YEAR, Unit::Year)
         } else if secs < MEGA_YEAR {
-            fmt_100(secs, KILO_YEAR, "ky", f)
+            fmt_100(secs, KILO_YEAR, Unit::KiloYear)
         } else if secs < GIGA_YEAR {
             // TODO: find the best shift, this value was adjusted to pass unit tests
             let shift = 1;
-            fmt_100(secs >> shift, MEGA_YEAR >> shift, "My", f)
+            fmt_100(secs >> shift, MEGA_YEAR >> shift, Unit::MegaYear)
         } else {
             // TODO: find the best shift, this value was adjusted to pass unit tests
             let shift = 8;
-            fmt_100(secs >> shift, GIGA_YEAR >> shift, "Gy", f)
-        }
+            fmt_100(secs >> shift, GIGA_YEAR >> shift, Unit::GigaYear)
+        };
+        Parts::one(part)
+    }
+}
+
+impl<S> Duration<S> {
+    pub fn fmt_one_unit_frac(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", OneUnitFrac.parts(self.0))
     }
 }
 
@@ -1,54 +1,68 @@
-use super::{Duration, DAY, GIGA_YEAR, HOUR, KILO_YEAR, MEGA_YEAR, MIN, MONTH, MS, US, WEEK, YEAR};
+use super::{
+    Duration, DurationStyle, Part, Parts, Unit, DAY, GIGA_YEAR, HOUR, KILO_YEAR, MEGA_YEAR, MIN,
+    MONTH, MS, US, WEEK, YEAR,
+};
 
-impl Duration {
-    pub fn fmt_one_unit_whole(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let secs = self.0.as_secs();
-        let ns = self.0.subsec_nanos();
+/// [Style::OneUnitWhole](super::Style::OneUnitWhole) as a [DurationStyle].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OneUnitWhole;
+
+impl DurationStyle for OneUnitWhole {
+    fn parts(&self, d: std::time::Duration) -> Parts {
+        let secs = d.as_secs();
+        let ns = d.subsec_nanos();
 
-        if secs < 1 {
+        let part = if secs < 1 {
             if ns < US {
                 if ns == 0 {
-                    write!(f, "0s")
+                    Part::whole(0, Unit::Second)
                 } else {
-                    write!(f, "{ns}ns")
+                    Part::whole(ns as u64, Unit::Nanosecond)
                 }
             } else if ns < MS {
                 let us = ns / US;
-                write!(f, "{us}us")
+                Part::whole(us as u64, Unit::Microsecond)
             } else {
                 let ms = ns / MS;
-                write!(f, "{ms}ms")
+                Part::whole(ms as u64, Unit::Millisecond)
             }
         } else if secs < MIN {
-            write!(f, "{secs}s")
+            Part::whole(secs, Unit::Second)
         } else if secs < HOUR {
             let mins = secs / MIN;
-            write!(f, "{mins}m")
+            Part::whole(mins, Unit::Minute)
         } else if secs < DAY {
             let hours = secs / HOUR;
-            write!(f, "{hours}h")
+            Part::whole(hours, Unit::Hour)
         } else if secs < WEEK {
             let days = secs / DAY;
-            write!(f, "{days}d")
+            Part::whole(days, Unit::Day)
         } else if secs < MONTH {
             let weeks = secs / WEEK;
-            write!(f, "{weeks}w")
+            Part::whole(weeks, Unit::Week)
         } else if secs < YEAR {
             let months = secs / MONTH;
-            write!(f, "{months}mo")
+            Part::whole(months, Unit::Month)
         } else if secs < KILO_YEAR {
             let years = secs / YEAR;
-            write!(f, "{years}y")
+            Part::whole(years, Unit::Year)
         } else if secs < MEGA_YEAR {
             let kilo_years = secs / KILO_YEAR;
-            write!(f, "{kilo_years}ky")
+            Part::whole(kilo_years, Unit::KiloYear)
         } else if secs < GIGA_YEAR {
             let mega_years = secs / MEGA_YEAR;
-            write!(f, "{mega_years}My")
+            Part::whole(mega_years, Unit::MegaYear)
         } else {
             let giga_years = secs / GIGA_YEAR;
-            write!(f, "{giga_years}Gy")
-        }
+            Part::whole(giga_years, Unit::GigaYear)
+        };
+        Parts::one(part)
+    }
+}
+
+impl<S> Duration<S> {
+    pub fn fmt_one_unit_whole(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", OneUnitWhole.parts(self.0))
     }
 }
 
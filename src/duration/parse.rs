@@ -0,0 +1,228 @@
+use super::{from_nanos, Duration, Style, Unit};
+use std::{fmt::Display, str::FromStr};
+
+/// Error returned when parsing a duration or a unit fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input has no values.
+    Empty,
+    /// A value is not a valid decimal number.
+    InvalidNumber(String),
+    /// A value has no unit.
+    MissingUnit(String),
+    /// A unit is not known.
+    UnknownUnit(String),
+    /// A style is not known.
+    UnknownStyle(String),
+    /// The duration does not fit into [std::time::Duration].
+    Overflow,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "empty duration"),
+            ParseError::InvalidNumber(s) => write!(f, "invalid number `{s}`"),
+            ParseError::MissingUnit(s) => write!(f, "missing unit after `{s}`"),
+            ParseError::UnknownUnit(s) => write!(f, "unknown unit `{s}`"),
+            ParseError::UnknownStyle(s) => write!(f, "unknown style `{s}`"),
+            ParseError::Overflow => write!(f, "duration is too large"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl FromStr for Unit {
+    type Err = ParseError;
+
+    /// Parse a unit from its symbol (`"ms"`) or name (`"millisecond"`, `"milliseconds"`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unit = match s {
+            "µs" | "μs" => Some(Unit::Microsecond),
+            "min" | "mins" => Some(Unit::Minute),
+            "sec" | "secs" => Some(Unit::Second),
+            "hr" | "hrs" => Some(Unit::Hour),
+            _ => None,
+        };
+        unit.or_else(|| {
+            Unit::LADDER
+                .into_iter()
+                .find(|u| u.symbol() == s || u.name() == s || u.plural() == s)
+        })
+        .ok_or_else(|| ParseError::UnknownUnit(s.to_string()))
+    }
+}
+
+impl FromStr for Style {
+    type Err = ParseError;
+
+    /// Parse a style from its name in kebab case, e.g. `"one-unit-frac"`.
+    ///
+    /// # Example
+    /// ```
+    /// use folktime::duration::Style;
+    ///
+    /// assert_eq!("two-units-whole".parse(), Ok(Style::TwoUnitsWhole));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "one-unit-frac" => Ok(Style::OneUnitFrac),
+            "one-unit-whole" => Ok(Style::OneUnitWhole),
+            "two-units-whole" => Ok(Style::TwoUnitsWhole),
+            _ => Err(ParseError::UnknownStyle(s.to_string())),
+        }
+    }
+}
+
+/// Parse a decimal number of `unit`s into nanoseconds, without losing precision.
+fn decimal_nanos(number: &str, unit: Unit) -> Result<u128, ParseError> {
+    let invalid = || ParseError::InvalidNumber(number.to_string());
+    let (whole, frac) = number.split_once('.').unwrap_or((number, ""));
+    if whole.is_empty() && frac.is_empty() {
+        return Err(invalid());
+    }
+    let digits = whole.chars().chain(frac.chars());
+    let mut value: u128 = 0;
+    for c in digits {
+        let digit = c.to_digit(10).ok_or_else(invalid)?;
+        value = value
+            .checked_mul(10)
+            .and_then(|v| v.checked_add(digit as u128))
+            .ok_or(ParseError::Overflow)?;
+    }
+    let scale = 10u128
+        .checked_pow(frac.len() as u32)
+        .ok_or(ParseError::Overflow)?;
+    value
+        .checked_mul(unit.as_nanos())
+        .map(|v| v / scale)
+        .ok_or(ParseError::Overflow)
+}
+
+/// Parse a duration written as values with units, e.g. `"2.05m"`, `"1h 2m"` or `"3 hours"`.
+///
+/// Accepts the output of the built-in styles. Values are added up, so `"1h 1h"` is two hours.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::duration::parse;
+///
+/// assert_eq!(parse("2.05m").unwrap(), Duration::from_secs(123));
+/// assert_eq!(parse("12ms 56us").unwrap(), Duration::from_micros(12_056));
+/// assert_eq!(parse("1h02m").unwrap(), Duration::from_secs(3720));
+/// assert_eq!(parse("2 hours").unwrap(), Duration::from_secs(7200));
+/// assert!(parse("5 parsecs").is_err());
+/// ```
+pub fn parse(s: &str) -> Result<std::time::Duration, ParseError> {
+    let mut rest = s.trim();
+    if rest.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let mut total: u128 = 0;
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let (number, after) = rest.split_at(number_len);
+        if number.is_empty() {
+            return Err(ParseError::InvalidNumber(rest.to_string()));
+        }
+        let after = after.trim_start();
+        let unit_len = after
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(after.len());
+        let (unit, after) = after.split_at(unit_len);
+        if unit.is_empty() {
+            return Err(ParseError::MissingUnit(number.to_string()));
+        }
+        let nanos = decimal_nanos(number, unit.parse()?)?;
+        total = total.checked_add(nanos).ok_or(ParseError::Overflow)?;
+        rest = after.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+    }
+    if total > std::time::Duration::MAX.as_nanos() {
+        return Err(ParseError::Overflow);
+    }
+    Ok(from_nanos(total))
+}
+
+impl FromStr for Duration {
+    type Err = ParseError;
+
+    /// Parse a duration with [parse] and format it with the default [Style](super::Style).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s).map(Duration::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Folktime;
+    use std::time::Duration;
+
+    #[test]
+    fn units() {
+        assert_eq!(parse("1ns").unwrap(), Duration::from_nanos(1));
+        assert_eq!(parse("1us").unwrap(), Duration::from_micros(1));
+        assert_eq!(parse("1µs").unwrap(), Duration::from_micros(1));
+        assert_eq!(parse("1ms").unwrap(), Duration::from_millis(1));
+        assert_eq!(parse("1s").unwrap(), Duration::from_secs(1));
+        assert_eq!(parse("1m").unwrap(), Duration::from_secs(60));
+        assert_eq!(parse("1h").unwrap(), Duration::from_secs(3600));
+        assert_eq!(parse("1d").unwrap(), Duration::from_secs(86400));
+        assert_eq!(parse("1w").unwrap(), Duration::from_secs(604800));
+        assert_eq!(parse("1mo").unwrap(), Duration::from_secs(2_629_846));
+        assert_eq!(parse("1y").unwrap(), Duration::from_secs(31_558_150));
+    }
+    #[test]
+    fn names() {
+        assert_eq!(parse("1 second").unwrap(), Duration::from_secs(1));
+        assert_eq!(parse("3 minutes").unwrap(), Duration::from_secs(180));
+        assert_eq!(parse("1 hour, 5 min").unwrap(), Duration::from_secs(3900));
+    }
+    #[test]
+    fn decimals() {
+        assert_eq!(parse("1.5s").unwrap(), Duration::from_millis(1500));
+        assert_eq!(parse(".5s").unwrap(), Duration::from_millis(500));
+        assert_eq!(
+            parse("2.").unwrap_err(),
+            ParseError::MissingUnit("2.".into())
+        );
+        assert_eq!(parse("0.0000000001s").unwrap(), Duration::ZERO);
+    }
+    #[test]
+    fn round_trip() {
+        for secs in [0, 1, 59, 61, 3599, 3601, 86399, 1_000_000] {
+            let d = Folktime::duration(Duration::from_secs(secs))
+                .with_style(crate::duration::Style::TwoUnitsWhole);
+            let parsed = parse(&d.to_string()).unwrap();
+            assert!(parsed <= Duration::from_secs(secs));
+        }
+    }
+    #[test]
+    fn errors() {
+        assert_eq!(parse("").unwrap_err(), ParseError::Empty);
+        assert_eq!(parse("  ").unwrap_err(), ParseError::Empty);
+        assert_eq!(parse("5").unwrap_err(), ParseError::MissingUnit("5".into()));
+        assert_eq!(
+            parse("5 parsecs").unwrap_err(),
+            ParseError::UnknownUnit("parsecs".into())
+        );
+        assert_eq!(
+            parse("1..2s").unwrap_err(),
+            ParseError::InvalidNumber("1..2".into())
+        );
+        assert_eq!(
+            parse("s").unwrap_err(),
+            ParseError::InvalidNumber("s".into())
+        );
+        assert_eq!(parse("600Gy").unwrap_err(), ParseError::Overflow);
+    }
+    #[test]
+    fn from_str() {
+        let d: crate::duration::Duration = "2m 3s".parse().unwrap();
+        assert_eq!(d.to_string(), "2.05m");
+    }
+}
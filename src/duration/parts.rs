@@ -0,0 +1,456 @@
+use super::{DAY, GIGA_YEAR, HOUR, KILO_YEAR, MEGA_YEAR, MIN, MONTH, MS, US, WEEK, YEAR};
+use std::fmt::Display;
+
+pub(crate) const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Convert nanoseconds into [std::time::Duration], saturating at [std::time::Duration::MAX].
+pub(crate) fn from_nanos(nanos: u128) -> std::time::Duration {
+    match u64::try_from(nanos / NANOS_PER_SEC) {
+        Ok(secs) => std::time::Duration::new(secs, (nanos % NANOS_PER_SEC) as u32),
+        Err(_) => std::time::Duration::MAX,
+    }
+}
+
+/// Unit of time chosen when formatting a [std::time::Duration].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Unit {
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+    KiloYear,
+    MegaYear,
+    GigaYear,
+}
+
+impl Unit {
+    /// All units, from the smallest to the largest.
+    pub const LADDER: [Unit; 13] = [
+        Unit::Nanosecond,
+        Unit::Microsecond,
+        Unit::Millisecond,
+        Unit::Second,
+        Unit::Minute,
+        Unit::Hour,
+        Unit::Day,
+        Unit::Week,
+        Unit::Month,
+        Unit::Year,
+        Unit::KiloYear,
+        Unit::MegaYear,
+        Unit::GigaYear,
+    ];
+
+    /// Length of the unit in nanoseconds.
+    pub const fn as_nanos(self) -> u128 {
+        match self {
+            Unit::Nanosecond => 1,
+            Unit::Microsecond => US as u128,
+            Unit::Millisecond => MS as u128,
+            Unit::Second => NANOS_PER_SEC,
+            Unit::Minute => MIN as u128 * NANOS_PER_SEC,
+            Unit::Hour => HOUR as u128 * NANOS_PER_SEC,
+            Unit::Day => DAY as u128 * NANOS_PER_SEC,
+            Unit::Week => WEEK as u128 * NANOS_PER_SEC,
+            Unit::Month => MONTH as u128 * NANOS_PER_SEC,
+            Unit::Year => YEAR as u128 * NANOS_PER_SEC,
+            Unit::KiloYear => KILO_YEAR as u128 * NANOS_PER_SEC,
+            Unit::MegaYear => MEGA_YEAR as u128 * NANOS_PER_SEC,
+            Unit::GigaYear => GIGA_YEAR as u128 * NANOS_PER_SEC,
+        }
+    }
+
+    /// The next smaller unit on the [ladder](Unit::LADDER).
+    pub fn smaller(self) -> Option<Unit> {
+        (self as usize).checked_sub(1).map(|i| Self::LADDER[i])
+    }
+
+    /// The next larger unit on the [ladder](Unit::LADDER).
+    pub fn larger(self) -> Option<Unit> {
+        Self::LADDER.get(self as usize + 1).copied()
+    }
+
+    /// The largest unit that fits into `d`, or [Unit::Second] for zero.
+    ///
+    /// This is the unit the built-in styles lead with.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use folktime::duration::Unit;
+    ///
+    /// assert_eq!(Unit::largest_in(Duration::from_secs(123)), Unit::Minute);
+    /// assert_eq!(Unit::largest_in(Duration::from_micros(5)), Unit::Microsecond);
+    /// assert_eq!(Unit::largest_in(Duration::ZERO), Unit::Second);
+    /// ```
+    pub fn largest_in(d: std::time::Duration) -> Unit {
+        let nanos = d.as_nanos();
+        if nanos == 0 {
+            return Unit::Second;
+        }
+        Self::LADDER
+            .into_iter()
+            .rev()
+            .find(|unit| unit.as_nanos() <= nanos)
+            .unwrap_or(Unit::Nanosecond)
+    }
+
+    /// Number of whole units in `d` and the remainder.
+    ///
+    /// The number saturates at [u64::MAX].
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use folktime::duration::Unit;
+    ///
+    /// let (mins, rest) = Unit::Minute.split(Duration::from_secs(123));
+    /// assert_eq!(mins, 2);
+    /// assert_eq!(rest, Duration::from_secs(3));
+    /// ```
+    pub fn split(self, d: std::time::Duration) -> (u64, std::time::Duration) {
+        let nanos = d.as_nanos();
+        let unit = self.as_nanos();
+        let whole = u64::try_from(nanos / unit).unwrap_or(u64::MAX);
+        let rest = nanos % unit;
+        let rest =
+            std::time::Duration::new((rest / NANOS_PER_SEC) as u64, (rest % NANOS_PER_SEC) as u32);
+        (whole, rest)
+    }
+
+    /// Singular name of the unit, e.g. `"hour"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Unit::Nanosecond => "nanosecond",
+            Unit::Microsecond => "microsecond",
+            Unit::Millisecond => "millisecond",
+            Unit::Second => "second",
+            Unit::Minute => "minute",
+            Unit::Hour => "hour",
+            Unit::Day => "day",
+            Unit::Week => "week",
+            Unit::Month => "month",
+            Unit::Year => "year",
+            Unit::KiloYear => "thousand years",
+            Unit::MegaYear => "million years",
+            Unit::GigaYear => "billion years",
+        }
+    }
+
+    /// Plural name of the unit, e.g. `"hours"`.
+    pub const fn plural(self) -> &'static str {
+        match self {
+            Unit::Nanosecond => "nanoseconds",
+            Unit::Microsecond => "microseconds",
+            Unit::Millisecond => "milliseconds",
+            Unit::Second => "seconds",
+            Unit::Minute => "minutes",
+            Unit::Hour => "hours",
+            Unit::Day => "days",
+            Unit::Week => "weeks",
+            Unit::Month => "months",
+            Unit::Year => "years",
+            Unit::KiloYear => "thousand years",
+            Unit::MegaYear => "million years",
+            Unit::GigaYear => "billion years",
+        }
+    }
+
+    /// Indefinite article used with the singular name, `"a"` or `"an"`.
+    pub const fn article(self) -> &'static str {
+        match self {
+            Unit::Hour => "an",
+            _ => "a",
+        }
+    }
+
+    /// Short symbol used in formatted output.
+    ///
+    /// # Example
+    /// ```
+    /// use folktime::duration::Unit;
+    ///
+    /// assert_eq!(Unit::Millisecond.symbol(), "ms");
+    /// assert_eq!(Unit::Month.symbol(), "mo");
+    /// ```
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Unit::Nanosecond => "ns",
+            Unit::Microsecond => "us",
+            Unit::Millisecond => "ms",
+            Unit::Second => "s",
+            Unit::Minute => "m",
+            Unit::Hour => "h",
+            Unit::Day => "d",
+            Unit::Week => "w",
+            Unit::Month => "mo",
+            Unit::Year => "y",
+            Unit::KiloYear => "ky",
+            Unit::MegaYear => "My",
+            Unit::GigaYear => "Gy",
+        }
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// A single value with its unit, e.g. `2.05m` or `3s`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Part {
+    /// Whole part of the value.
+    pub whole: u64,
+    /// Fractional part of the value, as an integer with `frac_digits` digits.
+    pub frac: u32,
+    /// Number of fractional digits shown, `0` for whole values.
+    pub frac_digits: u8,
+    /// Unit of the value.
+    pub unit: Unit,
+}
+
+impl Part {
+    /// A part showing only a whole number.
+    pub const fn whole(whole: u64, unit: Unit) -> Self {
+        Self {
+            whole,
+            frac: 0,
+            frac_digits: 0,
+            unit,
+        }
+    }
+
+    /// A part with `frac_digits` fractional digits.
+    pub const fn frac(whole: u64, frac: u32, frac_digits: u8, unit: Unit) -> Self {
+        Self {
+            whole,
+            frac,
+            frac_digits,
+            unit,
+        }
+    }
+
+    /// `d` expressed in `unit` with `frac_digits` fractional digits, truncated.
+    ///
+    /// At most 9 fractional digits are shown. The whole number saturates at [u64::MAX], like
+    /// in [Unit::split], so choose a unit that fits, e.g. with [Unit::largest_in].
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use folktime::duration::{Part, Unit};
+    ///
+    /// let d = Duration::from_micros(12_345_678);
+    /// assert_eq!(Part::in_unit(d, Unit::Millisecond, 1).to_string(), "12345.6ms");
+    /// assert_eq!(Part::in_unit(d, Unit::Minute, 3).to_string(), "0.205m");
+    /// ```
+    pub fn in_unit(d: std::time::Duration, unit: Unit, frac_digits: u8) -> Self {
+        let frac_digits = frac_digits.min(9);
+        let (whole, rest) = unit.split(d);
+        let scale = 10u128.pow(frac_digits as u32);
+        let frac = (rest.as_nanos() * scale / unit.as_nanos()) as u32;
+        Self::frac(whole, frac, frac_digits, unit)
+    }
+
+    /// `d` expressed in `unit` with three significant digits, like
+    /// [Style::OneUnitFrac](super::Style::OneUnitFrac), but at most three fractional digits.
+    ///
+    /// Useful to show several durations in the same unit. The whole number saturates at
+    /// [u64::MAX], see [Part::in_unit].
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use folktime::duration::{Part, Unit};
+    ///
+    /// let ms = |micros| Part::significant(Duration::from_micros(micros), Unit::Millisecond);
+    /// assert_eq!(ms(512).to_string(), "0.512ms");
+    /// assert_eq!(ms(1_310).to_string(), "1.31ms");
+    /// assert_eq!(ms(12_150).to_string(), "12.1ms");
+    /// assert_eq!(ms(1_234_567).to_string(), "1234ms");
+    ///
+    /// let max = Part::significant(Duration::MAX, Unit::Nanosecond);
+    /// assert_eq!(max.to_string(), "18446744073709551615ns");
+    /// ```
+    pub fn significant(d: std::time::Duration, unit: Unit) -> Self {
+        let frac_digits = match d.as_nanos() / unit.as_nanos() {
+            0 => 3,
+            1..=9 => 2,
+            10..=99 => 1,
+            _ => 0,
+        };
+        Self::in_unit(d, unit, frac_digits)
+    }
+
+    /// Value of the part in nanoseconds, truncated and saturating at [u128::MAX].
+    pub(crate) fn as_nanos(&self) -> u128 {
+        let unit = self.unit.as_nanos();
+        // `frac` has at most 10 digits, so it is below one unit when the scale overflows.
+        let frac = match 10u128.checked_pow(self.frac_digits as u32) {
+            Some(scale) => self.frac as u128 * unit / scale,
+            None => 0,
+        };
+        (self.whole as u128)
+            .saturating_mul(unit)
+            .saturating_add(frac)
+    }
+
+    /// Value of the part in nanoseconds, scaled by `10^frac_digits` to stay exact.
+    ///
+    /// [None] on overflow, which custom styles can reach with large values or many digits.
+    fn scaled_nanos(&self, frac_digits: u8) -> Option<u128> {
+        let scale = 10u128.checked_pow((frac_digits - self.frac_digits) as u32)?;
+        (self.whole as u128)
+            .checked_mul(10u128.checked_pow(self.frac_digits as u32)?)?
+            .checked_add(self.frac as u128)?
+            .checked_mul(scale)?
+            .checked_mul(self.unit.as_nanos())
+    }
+}
+
+impl Part {
+    /// Writes the value without the unit, e.g. `2.05`.
+    pub(crate) fn fmt_value(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fmt_number(self.whole, self.frac as u128, self.frac_digits, f)
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.fmt_value(f)?;
+        write!(f, "{}", self.unit)
+    }
+}
+
+/// Writes a number with `frac_digits` fractional digits, e.g. `2.05`.
+pub(crate) fn fmt_number(
+    whole: impl Display,
+    frac: u128,
+    frac_digits: u8,
+    f: &mut std::fmt::Formatter,
+) -> std::fmt::Result {
+    match frac_digits {
+        0 => write!(f, "{whole}"),
+        digits => write!(f, "{whole}.{frac:0width$}", width = digits as usize),
+    }
+}
+
+/// Writes `num / den` with three significant digits, truncated like
+/// [Style::OneUnitFrac](super::Style::OneUnitFrac), e.g. `1.22`, `21.9` or `0.512`.
+///
+/// For numbers that are not durations, such as ratios and rates.
+pub(crate) fn fmt_quotient(num: u128, den: u128, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    // The quotient as seconds, to reuse the precision of `Part::significant`.
+    let secs = u64::try_from(num / den).unwrap_or(u64::MAX);
+    let rest = num % den;
+    let nanos = match rest.checked_mul(NANOS_PER_SEC) {
+        Some(rest) => rest / den,
+        None => rest / (den / NANOS_PER_SEC),
+    };
+    let d = std::time::Duration::new(secs, nanos as u32);
+    Part::significant(d, Unit::Second).fmt_value(f)
+}
+
+/// The parts chosen by a style for a duration: the numeric decomposition of the value.
+///
+/// For the built-in [Style](super::Style)s, formatting [Parts] gives the same output as
+/// formatting the [Duration](super::Duration) they were taken from. Styles that write
+/// words or wrap another style can differ, e.g. [Fraction](super::Fraction) has the parts
+/// `0.33h` for the text "a third of an hour".
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::Folktime;
+/// use folktime::duration::{Part, Style, Unit};
+///
+/// let parts = Folktime::duration(Duration::from_secs(123)).parts();
+/// assert_eq!(parts.first(), Part::frac(2, 5, 2, Unit::Minute));
+/// assert_eq!(parts.second(), None);
+///
+/// let parts = Folktime::duration(Duration::from_secs(123))
+///     .with_style(Style::TwoUnitsWhole)
+///     .parts();
+/// assert_eq!(parts.first(), Part::whole(2, Unit::Minute));
+/// assert_eq!(parts.second(), Some(Part::whole(3, Unit::Second)));
+/// assert_eq!(format!("{}", parts), "2m 3s");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Parts {
+    first: Part,
+    second: Option<Part>,
+}
+
+impl Parts {
+    pub const fn one(first: Part) -> Self {
+        Self {
+            first,
+            second: None,
+        }
+    }
+
+    pub const fn two(first: Part, second: Part) -> Self {
+        Self {
+            first,
+            second: Some(second),
+        }
+    }
+
+    /// The most significant part.
+    pub const fn first(&self) -> Part {
+        self.first
+    }
+
+    /// The less significant part, if the style shows one.
+    pub const fn second(&self) -> Option<Part> {
+        self.second
+    }
+
+    /// Iterate over the parts from the most significant one.
+    pub fn iter(&self) -> impl Iterator<Item = Part> {
+        std::iter::once(self.first).chain(self.second)
+    }
+
+    /// Whether the parts show `d` without losing any precision.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use folktime::Folktime;
+    ///
+    /// let d = Duration::from_secs(90);
+    /// assert!(Folktime::duration(d).parts().is_exact(d));
+    ///
+    /// let d = Duration::from_secs(124);
+    /// assert!(!Folktime::duration(d).parts().is_exact(d));
+    /// ```
+    ///
+    /// Parts too large to compare without overflowing are not exact.
+    pub fn is_exact(&self, d: std::time::Duration) -> bool {
+        let digits = self.iter().map(|p| p.frac_digits).max().unwrap_or(0);
+        let shown = self
+            .iter()
+            .try_fold(0u128, |sum, p| sum.checked_add(p.scaled_nanos(digits)?));
+        let expected = 10u128
+            .checked_pow(digits as u32)
+            .and_then(|scale| d.as_nanos().checked_mul(scale));
+        matches!((shown, expected), (Some(shown), Some(expected)) if shown == expected)
+    }
+}
+
+impl Display for Parts {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.second {
+            Some(second) => write!(f, "{} {}", self.first, second),
+            None => write!(f, "{}", self.first),
+        }
+    }
+}
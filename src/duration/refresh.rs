@@ -0,0 +1,171 @@
+use super::{from_nanos, Duration, DurationStyle};
+
+/// Smallest step in `1..=limit` nanoseconds after which `render` gives a different output
+/// than at step zero.
+///
+/// The output must not go back to what it was, which holds for styles that truncate.
+pub(crate) fn first_change(limit: u128, render: impl Fn(u128) -> String) -> Option<u128> {
+    let start = render(0);
+    let differs = |step| render(step) != start;
+    // Double the step until the output differs, then narrow it down.
+    let mut low = 0;
+    let mut high = 1.min(limit);
+    while high > 0 && !differs(high) {
+        if high == limit {
+            return None;
+        }
+        low = high;
+        high = high.saturating_mul(2).min(limit);
+    }
+    if high == 0 {
+        return None;
+    }
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if differs(mid) {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    Some(high)
+}
+
+impl<S: DurationStyle> Duration<S> {
+    /// How much the duration has to grow for the formatted output to change, e.g. to
+    /// schedule the next repaint of an elapsed time. `None` if it never changes.
+    ///
+    /// This assumes the output does not go back to an earlier value, as with the
+    /// built-in styles, which truncate.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use folktime::Folktime;
+    /// use folktime::duration::Style;
+    ///
+    /// let d = Folktime::duration(Duration::from_secs(123));
+    /// assert_eq!(d.to_string(), "2.05m");
+    /// assert_eq!(d.next_change(), Some(Duration::from_millis(600)));
+    ///
+    /// let d = Folktime::duration(Duration::from_secs(200)).with_style(Style::OneUnitWhole);
+    /// assert_eq!(d.next_change(), Some(Duration::from_secs(40)));
+    /// ```
+    pub fn next_change(&self) -> Option<std::time::Duration> {
+        let d = self.0.as_nanos();
+        let limit = std::time::Duration::MAX.as_nanos() - d;
+        first_change(limit, |step| {
+            Duration(from_nanos(d + step), &self.1).to_string()
+        })
+        .map(from_nanos)
+    }
+
+    /// How much the duration has to shrink for the formatted output to change, e.g. to
+    /// schedule the next repaint of a countdown. `None` if it never changes.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use folktime::Folktime;
+    ///
+    /// let d = Folktime::duration(Duration::from_millis(5_004));
+    /// assert_eq!(d.to_string(), "5.00s");
+    /// assert_eq!(d.next_change_shrinking(), Some(Duration::from_nanos(4_000_001)));
+    /// ```
+    pub fn next_change_shrinking(&self) -> Option<std::time::Duration> {
+        let d = self.0.as_nanos();
+        first_change(d, |step| {
+            Duration(from_nanos(d - step), &self.1).to_string()
+        })
+        .map(from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        duration::{Approx, Style},
+        Folktime,
+    };
+    use std::time::Duration;
+
+    fn next(d: Duration, style: Style) -> Option<Duration> {
+        Folktime::duration(d).with_style(style).next_change()
+    }
+
+    #[test]
+    fn one_unit_frac() {
+        let style = Style::OneUnitFrac;
+        assert_eq!(next(Duration::ZERO, style), Some(Duration::from_nanos(1)));
+        assert_eq!(
+            next(Duration::from_millis(5_000), style),
+            Some(Duration::from_millis(10))
+        );
+        assert_eq!(
+            next(Duration::from_millis(59_950), style),
+            Some(Duration::from_millis(50))
+        );
+        assert_eq!(
+            next(Duration::from_secs(3_600), style),
+            Some(Duration::from_secs(36))
+        );
+        assert_eq!(
+            next(Duration::from_nanos(999), style),
+            Some(Duration::from_nanos(1))
+        );
+        assert_eq!(
+            next(Duration::from_micros(12_345), style),
+            Some(Duration::from_micros(55))
+        );
+    }
+    #[test]
+    fn whole_styles() {
+        let secs = Duration::from_secs;
+        assert_eq!(next(secs(200), Style::OneUnitWhole), Some(secs(40)));
+        assert_eq!(next(secs(5_415), Style::TwoUnitsWhole), Some(secs(45)));
+        assert_eq!(next(secs(59), Style::TwoUnitsWhole), Some(secs(1) / 1_000));
+    }
+    #[test]
+    fn consistent() {
+        for secs in [1, 59, 61, 599, 3_599, 86_399, 1_000_000, 40_000_000] {
+            let d = Duration::from_secs(secs) + Duration::from_millis(secs % 1_000);
+            for style in [
+                Style::OneUnitFrac,
+                Style::OneUnitWhole,
+                Style::TwoUnitsWhole,
+            ] {
+                let fmt = |d| Folktime::duration(d).with_style(style).to_string();
+                let step = next(d, style).unwrap();
+                assert_ne!(fmt(d + step), fmt(d));
+                assert_eq!(fmt(d + step - Duration::from_nanos(1)), fmt(d));
+            }
+        }
+    }
+    #[test]
+    fn shrinking() {
+        let d = Folktime::duration(Duration::from_secs(90)).with_style(Style::TwoUnitsWhole);
+        assert_eq!(d.next_change_shrinking(), Some(Duration::from_nanos(1)));
+        let d = Folktime::duration(Duration::from_millis(90_500)).with_style(Style::OneUnitWhole);
+        assert_eq!(
+            d.next_change_shrinking(),
+            Some(Duration::from_millis(30_500) + Duration::from_nanos(1))
+        );
+        assert_eq!(
+            Folktime::duration(Duration::ZERO).next_change_shrinking(),
+            None
+        );
+    }
+    #[test]
+    fn limits() {
+        assert_eq!(Folktime::duration(Duration::MAX).next_change(), None);
+        let d = Folktime::duration(Duration::MAX - Duration::from_secs(1));
+        assert_eq!(d.next_change(), None);
+    }
+    #[test]
+    fn custom_style() {
+        let d =
+            Folktime::duration(Duration::from_secs(90)).with_style(Approx::new(Style::OneUnitFrac));
+        assert_eq!(d.to_string(), "1.50m");
+        assert_eq!(d.next_change(), Some(Duration::from_nanos(1)));
+    }
+}
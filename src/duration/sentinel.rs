@@ -0,0 +1,227 @@
+use super::{pad, Duration, DurationStyle, Parts, Style};
+use std::fmt::Display;
+
+/// Shows durations used as a sentinel, such as [std::time::Duration::MAX] for "no timeout",
+/// as text instead of a value, e.g. `∞` or `never`.
+///
+/// By default only [std::time::Duration::MAX] is a sentinel. With
+/// [Forever::with_threshold], every value at or above the threshold is.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::Folktime;
+/// use folktime::duration::{Forever, Style};
+///
+/// let a = Folktime::duration(Duration::MAX).forever();
+/// let b = Folktime::duration(Duration::from_secs(5)).forever();
+/// let c = Folktime::duration(Duration::from_secs(400 * 86_400)).with_style(
+///     Forever::new(Style::OneUnitWhole)
+///         .with_threshold(Duration::from_secs(365 * 86_400))
+///         .with_text("never"),
+/// );
+///
+/// assert_eq!(format!("{}", a), "∞");
+/// assert_eq!(format!("{}", b), "5.00s");
+/// assert_eq!(format!("{}", c), "never");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Forever<S> {
+    style: S,
+    threshold: std::time::Duration,
+    text: &'static str,
+}
+
+impl<S> Forever<S> {
+    /// Wrap `style`, showing [std::time::Duration::MAX] as `∞`.
+    pub const fn new(style: S) -> Self {
+        Self {
+            style,
+            threshold: std::time::Duration::MAX,
+            text: "∞",
+        }
+    }
+
+    /// Treat values at or above `threshold` as a sentinel.
+    pub fn with_threshold(self, threshold: std::time::Duration) -> Self {
+        Self { threshold, ..self }
+    }
+
+    /// Set the text shown for a sentinel, e.g. `"never"` or `"forever"`.
+    pub fn with_text(self, text: &'static str) -> Self {
+        Self { text, ..self }
+    }
+
+    /// Whether `d` is shown as the sentinel text.
+    pub fn is_forever(&self, d: std::time::Duration) -> bool {
+        d >= self.threshold
+    }
+}
+
+impl<S: DurationStyle> DurationStyle for Forever<S> {
+    fn parts(&self, d: std::time::Duration) -> Parts {
+        self.style.parts(d)
+    }
+
+    fn fmt(&self, d: std::time::Duration, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_forever(d) {
+            f.write_str(self.text)
+        } else {
+            self.style.fmt(d, f)
+        }
+    }
+
+    fn fmt_sub_nanos(&self, attos: u64, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        // Only a threshold of zero is below a nanosecond.
+        if self.is_forever(std::time::Duration::ZERO) {
+            f.write_str(self.text)
+        } else {
+            self.style.fmt_sub_nanos(attos, f)
+        }
+    }
+
+    fn is_exact_sub_nanos(&self, attos: u64) -> bool {
+        self.style.is_exact_sub_nanos(attos)
+    }
+}
+
+impl<S: DurationStyle> Duration<S> {
+    /// Show [std::time::Duration::MAX] as `∞`, see [Forever].
+    pub fn forever(self) -> Duration<Forever<S>> {
+        Duration(self.0, Forever::new(self.1))
+    }
+}
+
+/// A duration that may be missing, shown as a placeholder when it is, `—` by default.
+///
+/// Formatting honors width and alignment for the placeholder as well, so missing values
+/// line up in tables.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::Folktime;
+/// use folktime::duration::{Forever, Style};
+///
+/// let timeouts = [Some(Duration::from_secs(30)), None, Some(Duration::MAX)];
+/// let cells: Vec<_> = timeouts
+///     .into_iter()
+///     .map(|t| {
+///         let t = Folktime::optional(t).with_style(Forever::new(Style::OneUnitWhole));
+///         format!("{:>4}", t)
+///     })
+///     .collect();
+/// assert_eq!(cells, [" 30s", "   —", "   ∞"]);
+///
+/// let t = Folktime::optional(None).with_placeholder("n/a");
+/// assert_eq!(format!("{}", t), "n/a");
+/// ```
+pub struct Optional<S = Style> {
+    value: Option<std::time::Duration>,
+    style: S,
+    placeholder: &'static str,
+}
+
+impl Optional {
+    pub fn new(value: Option<std::time::Duration>) -> Self {
+        Self {
+            value,
+            style: Style::default(),
+            placeholder: "—",
+        }
+    }
+}
+
+impl<S> Optional<S> {
+    /// Set the formatting style of a present value.
+    pub fn with_style<T: DurationStyle>(self, style: T) -> Optional<T> {
+        Optional {
+            value: self.value,
+            style,
+            placeholder: self.placeholder,
+        }
+    }
+
+    /// Set the text shown for a missing value, e.g. `"n/a"`.
+    pub fn with_placeholder(self, placeholder: &'static str) -> Self {
+        Self {
+            placeholder,
+            ..self
+        }
+    }
+
+    pub fn value(&self) -> Option<std::time::Duration> {
+        self.value
+    }
+}
+
+impl<S: DurationStyle> Display for Optional<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.value {
+            Some(d) => Display::fmt(&Duration(d, &self.style), f),
+            None => pad(self.placeholder, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Folktime;
+    use std::time::Duration;
+
+    #[test]
+    fn max() {
+        let d = Folktime::duration(Duration::MAX);
+        assert_eq!(d.to_string(), "584Gy");
+        assert_eq!(d.forever().to_string(), "∞");
+        let d = Folktime::duration(Duration::MAX - Duration::from_nanos(1)).forever();
+        assert_eq!(d.to_string(), "584Gy");
+        let d = Folktime::duration(Duration::MAX).with_style(Forever::new(Style::TwoUnitsWhole));
+        assert_eq!(format!("[{:^5}]", d), "[  ∞  ]");
+    }
+    #[test]
+    fn threshold() {
+        let style = Forever::new(Style::OneUnitFrac)
+            .with_threshold(Duration::from_secs(86_400))
+            .with_text("forever");
+        let fmt = |secs| {
+            Folktime::duration(Duration::from_secs(secs))
+                .with_style(style)
+                .to_string()
+        };
+        assert_eq!(fmt(86_399), "23.9h");
+        assert_eq!(fmt(86_400), "forever");
+        assert_eq!(fmt(u64::MAX), "forever");
+        assert!(style.is_forever(Duration::MAX));
+        assert!(!style.is_forever(Duration::ZERO));
+    }
+    #[test]
+    fn parts() {
+        let d = Folktime::duration(Duration::from_secs(123)).forever();
+        assert_eq!(d.parts().to_string(), "2.05m");
+    }
+    #[test]
+    fn optional() {
+        let some = Folktime::optional(Some(Duration::from_millis(1_500)));
+        assert_eq!(some.to_string(), "1.50s");
+        assert_eq!(some.value(), Some(Duration::from_millis(1_500)));
+        let none = Folktime::optional(None);
+        assert_eq!(none.to_string(), "—");
+        assert_eq!(format!("[{:<3}]", none), "[—  ]");
+        assert_eq!(none.value(), None);
+        let none = Folktime::optional(None)
+            .with_placeholder("n/a")
+            .with_style(Style::TwoUnitsWhole);
+        assert_eq!(format!("[{:>5}]", none), "[  n/a]");
+        assert_eq!(format!("[{:>5.1}]", none), "[  n/a]");
+    }
+    #[test]
+    fn optional_with_style() {
+        let t = Folktime::optional(Some(Duration::from_secs(90))).with_style(Style::TwoUnitsWhole);
+        assert_eq!(format!("[{:>8}]", t), "[  1m 30s]");
+        let t = Folktime::optional(Some(Duration::MAX))
+            .with_style(Forever::new(Style::OneUnitWhole).with_text("never"));
+        assert_eq!(t.to_string(), "never");
+    }
+}
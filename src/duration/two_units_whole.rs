@@ -1,69 +1,86 @@
-use super::{Duration, DAY, GIGA_YEAR, HOUR, KILO_YEAR, MEGA_YEAR, MIN, MONTH, MS, US, WEEK, YEAR};
+use super::{
+    Duration, DurationStyle, Part, Parts, Unit, DAY, GIGA_YEAR, HOUR, KILO_YEAR, MEGA_YEAR, MIN,
+    MONTH, MS, US, WEEK, YEAR,
+};
 
-impl Duration {
-    pub fn fmt_two_units_whole(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let secs = self.0.as_secs();
-        let ns = self.0.subsec_nanos();
+fn two(big: u64, big_unit: Unit, small: u64, small_unit: Unit) -> Parts {
+    Parts::two(Part::whole(big, big_unit), Part::whole(small, small_unit))
+}
+
+/// [Style::TwoUnitsWhole](super::Style::TwoUnitsWhole) as a [DurationStyle].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TwoUnitsWhole;
+
+impl DurationStyle for TwoUnitsWhole {
+    fn parts(&self, d: std::time::Duration) -> Parts {
+        let secs = d.as_secs();
+        let ns = d.subsec_nanos();
 
         if secs < 1 {
             if ns < US {
                 if ns == 0 {
-                    write!(f, "0s 0ms")
+                    two(0, Unit::Second, 0, Unit::Millisecond)
                 } else {
-                    write!(f, "{}ns", ns)
+                    Parts::one(Part::whole(ns as u64, Unit::Nanosecond))
                 }
             } else if ns < MS {
                 let us = ns / US;
                 let ns = ns % US;
-                write!(f, "{us}us {ns}ns")
+                two(us as u64, Unit::Microsecond, ns as u64, Unit::Nanosecond)
             } else {
                 let ms = ns / MS;
                 let us = (ns % MS) / US;
-                write!(f, "{ms}ms {us}us")
+                two(ms as u64, Unit::Millisecond, us as u64, Unit::Microsecond)
             }
         } else if secs < MIN {
             let ms = ns / 1_000_000;
-            write!(f, "{secs}s {ms}ms")
+            two(secs, Unit::Second, ms as u64, Unit::Millisecond)
         } else if secs < HOUR {
             let mins = secs / MIN;
             let secs = secs % MIN;
-            write!(f, "{mins}m {secs}s")
+            two(mins, Unit::Minute, secs, Unit::Second)
         } else if secs < DAY {
             let hours = secs / HOUR;
             let mins = (secs % HOUR) / MIN;
-            write!(f, "{hours}h {mins}m")
+            two(hours, Unit::Hour, mins, Unit::Minute)
         } else if secs < WEEK {
             let days = secs / DAY;
             let hours = (secs % DAY) / HOUR;
-            write!(f, "{days}d {hours}h")
+            two(days, Unit::Day, hours, Unit::Hour)
         } else if secs < MONTH {
             let weeks = secs / WEEK;
             let days = (secs % WEEK) / DAY;
-            write!(f, "{weeks}w {days}d")
+            two(weeks, Unit::Week, days, Unit::Day)
         } else if secs < YEAR {
             let months = secs / MONTH;
             let days = (secs % MONTH) / DAY;
-            write!(f, "{months}mo {days}d")
+            two(months, Unit::Month, days, Unit::Day)
         } else if secs < KILO_YEAR {
             let years = secs / YEAR;
             let months = (secs % YEAR) / MONTH;
-            write!(f, "{years}y {months}mo")
+            two(years, Unit::Year, months, Unit::Month)
         } else if secs < MEGA_YEAR {
             let kilo_years = secs / KILO_YEAR;
             let years = (secs % KILO_YEAR) / YEAR;
-            write!(f, "{kilo_years}ky {years}y")
+            two(kilo_years, Unit::KiloYear, years, Unit::Year)
         } else if secs < GIGA_YEAR {
             let mega_years = secs / MEGA_YEAR;
             let kilo_years = (secs % MEGA_YEAR) / KILO_YEAR;
-            write!(f, "{mega_years}My {kilo_years}ky")
+            two(mega_years, Unit::MegaYear, kilo_years, Unit::KiloYear)
         } else {
             let giga_years = secs / GIGA_YEAR;
             let mega_years = (secs % GIGA_YEAR) / MEGA_YEAR;
-            write!(f, "{giga_years}Gy {mega_years}My")
+            two(giga_years, Unit::GigaYear, mega_years, Unit::MegaYear)
         }
     }
 }
 
+impl<S> Duration<S> {
+    pub fn fmt_two_units_whole(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", TwoUnitsWhole.parts(self.0))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
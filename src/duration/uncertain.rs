@@ -0,0 +1,217 @@
+use super::{Part, Unit};
+use std::fmt::Display;
+
+/// A measured duration with its uncertainty, e.g. `1.31 ± 0.05ms`.
+///
+/// The digits shown follow the uncertainty, like in scientific notation: the uncertainty is
+/// rounded to one significant digit, or two when it starts with a 1, and the value is rounded
+/// to the same decimal place, also to tens or hundreds of the unit for large uncertainties.
+/// An uncertainty whose leading digit changes when rounded, e.g. 0.96 to 1, keeps one
+/// significant digit.
+/// Both share the largest unit that fits the bigger of the two.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::duration::Uncertain;
+///
+/// let ms = |micros| Duration::from_micros(micros);
+/// assert_eq!(Uncertain::new(ms(1_312), ms(48)).to_string(), "1.31 ± 0.05ms");
+/// assert_eq!(Uncertain::new(ms(1_312), ms(260)).to_string(), "1.3 ± 0.3ms");
+/// assert_eq!(Uncertain::new(ms(1_312), ms(120)).to_string(), "1.31 ± 0.12ms");
+/// assert_eq!(Uncertain::new(ms(12_312), ms(4_100)).to_string(), "12 ± 4ms");
+/// assert_eq!(Uncertain::new(ms(42_000), ms(25_000)).to_string(), "40 ± 30ms");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Uncertain {
+    value: std::time::Duration,
+    uncertainty: std::time::Duration,
+    unit: Unit,
+}
+
+impl Uncertain {
+    pub fn new(value: std::time::Duration, uncertainty: std::time::Duration) -> Self {
+        Self {
+            value,
+            uncertainty,
+            unit: Unit::largest_in(value.max(uncertainty)),
+        }
+    }
+
+    /// Show both numbers in `unit`.
+    ///
+    /// Units too small to count the bigger of the two in a [u64] are ignored.
+    pub fn with_unit(self, unit: Unit) -> Self {
+        let count = self.value.max(self.uncertainty).as_nanos() / unit.as_nanos();
+        // Rounding can carry the count to the next power of ten.
+        match u64::try_from(count).ok().and_then(|n| n.checked_mul(10)) {
+            Some(_) => Self { unit, ..self },
+            None => self,
+        }
+    }
+
+    pub fn value(&self) -> std::time::Duration {
+        self.value
+    }
+
+    pub fn uncertainty(&self) -> std::time::Duration {
+        self.uncertainty
+    }
+
+    pub fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    /// Number of fractional digits that match the uncertainty, at most 9.
+    pub fn frac_digits(&self) -> u8 {
+        (-self.place()).clamp(0, 9) as u8
+    }
+
+    /// Decimal place of the last digit shown, e.g. `-2` for hundredths of the unit or `1`
+    /// for tens.
+    fn place(&self) -> i32 {
+        let unit = self.unit.as_nanos();
+        let u = self.uncertainty.as_nanos();
+        if u == 0 {
+            // Exact, show three significant digits.
+            return match self.value.as_nanos() / unit {
+                0..=9 => -2,
+                10..=99 => -1,
+                _ => 0,
+            };
+        }
+        // The digit of the uncertainty at decimal place `e`, and any digits above it.
+        let digits = |e: i32| match e {
+            ..=0 => u * 10u128.pow(e.unsigned_abs()) / unit,
+            _ => u / (unit * 10u128.pow(e as u32)),
+        };
+        let mut leading = -9;
+        while digits(leading + 1) > 0 {
+            leading += 1;
+        }
+        // The uncertainty rounded to the place as a count of that place, and the count at
+        // which rounding changes the leading digit, e.g. 0.96 to 1.0 or 0.196 to 0.20.
+        let (place, carry) = match digits(leading) {
+            1 => ((leading - 1).max(-9), 20),
+            _ => (leading, 10),
+        };
+        let count = match place {
+            ..=0 => round_div(u * 10u128.pow(place.unsigned_abs()), unit),
+            _ => round_div(u, unit * 10u128.pow(place as u32)),
+        };
+        // The digits are counted again after such a carry, so 0.96 is 1 and 0.196 is 0.2.
+        match count == carry {
+            true => place + 1,
+            false => place,
+        }
+    }
+
+    /// `nanos` in the unit, rounded half up to the decimal `place`.
+    fn rounded(&self, nanos: u128, place: i32) -> Part {
+        let unit = self.unit.as_nanos();
+        let whole = |n: u128| u64::try_from(n).unwrap_or(u64::MAX);
+        if place >= 0 {
+            let step = 10u128.pow(place as u32);
+            Part::whole(whole(round_div(nanos, unit * step) * step), self.unit)
+        } else {
+            let scale = 10u128.pow(place.unsigned_abs());
+            let scaled = round_div(nanos * scale, unit);
+            let frac = (scaled % scale) as u32;
+            Part::frac(
+                whole(scaled / scale),
+                frac,
+                place.unsigned_abs() as u8,
+                self.unit,
+            )
+        }
+    }
+}
+
+/// `a / b` rounded half up.
+fn round_div(a: u128, b: u128) -> u128 {
+    (a + b / 2) / b
+}
+
+impl Display for Uncertain {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let place = self.place();
+        self.rounded(self.value.as_nanos(), place).fmt_value(f)?;
+        f.write_str(" ± ")?;
+        self.rounded(self.uncertainty.as_nanos(), place)
+            .fmt_value(f)?;
+        write!(f, "{}", self.unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn fmt(value: Duration, uncertainty: Duration) -> String {
+        Uncertain::new(value, uncertainty).to_string()
+    }
+
+    #[test]
+    fn digits_follow_uncertainty() {
+        let v = Duration::from_nanos(1_312_345);
+        assert_eq!(fmt(v, Duration::from_nanos(2)), "1.312345 ± 0.000002ms");
+        assert_eq!(fmt(v, Duration::from_micros(3)), "1.312 ± 0.003ms");
+        assert_eq!(fmt(v, Duration::from_micros(48)), "1.31 ± 0.05ms");
+        assert_eq!(fmt(v, Duration::from_micros(400)), "1.3 ± 0.4ms");
+        assert_eq!(fmt(v, Duration::from_micros(900)), "1.3 ± 0.9ms");
+        assert_eq!(fmt(v, Duration::from_micros(940)), "1.3 ± 0.9ms");
+        assert_eq!(fmt(v, Duration::from_micros(960)), "1 ± 1ms");
+    }
+    #[test]
+    fn leading_one() {
+        let v = Duration::from_millis(1_234);
+        assert_eq!(fmt(v, Duration::from_millis(12)), "1.234 ± 0.012s");
+        assert_eq!(fmt(v, Duration::from_millis(96)), "1.2 ± 0.1s");
+        assert_eq!(fmt(v, Duration::from_millis(196)), "1.2 ± 0.2s");
+        assert_eq!(fmt(v, Duration::from_millis(194)), "1.23 ± 0.19s");
+        assert_eq!(fmt(v, Duration::from_millis(150)), "1.23 ± 0.15s");
+        assert_eq!(fmt(v, Duration::from_millis(1_100)), "1.2 ± 1.1s");
+    }
+    #[test]
+    fn large_uncertainty() {
+        let v = Duration::from_secs(42);
+        assert_eq!(fmt(v, Duration::from_secs(25)), "40 ± 30s");
+        assert_eq!(fmt(v, Duration::from_secs(15)), "42 ± 15s");
+        assert_eq!(fmt(v, Duration::from_secs(90)), "0.7 ± 1.5m");
+        let u = Uncertain::new(Duration::from_secs(42), Duration::from_secs(25));
+        assert_eq!(u.frac_digits(), 0);
+        let u = Uncertain::new(
+            Duration::from_millis(4_567_890),
+            Duration::from_millis(345_000),
+        )
+        .with_unit(Unit::Millisecond);
+        assert_eq!(u.to_string(), "4600000 ± 300000ms");
+    }
+    #[test]
+    fn unit_too_small() {
+        let u = Uncertain::new(Duration::MAX, Duration::MAX);
+        assert_eq!(u.with_unit(Unit::Nanosecond), u);
+        assert_eq!(u.with_unit(Unit::Nanosecond).unit(), Unit::GigaYear);
+        let u = Uncertain::new(Duration::from_secs(u64::MAX / 100), Duration::ZERO);
+        assert_eq!(u.with_unit(Unit::Second).unit(), Unit::Second);
+        assert_eq!(u.with_unit(Unit::Millisecond).unit(), Unit::GigaYear);
+    }
+    #[test]
+    fn exact() {
+        assert_eq!(
+            fmt(Duration::from_micros(1_312), Duration::ZERO),
+            "1.31 ± 0.00ms"
+        );
+        assert_eq!(fmt(Duration::ZERO, Duration::ZERO), "0.00 ± 0.00s");
+    }
+    #[test]
+    fn unit() {
+        let u = Uncertain::new(Duration::from_micros(1_312), Duration::from_micros(48))
+            .with_unit(crate::duration::Unit::Microsecond);
+        assert_eq!(u.to_string(), "1310 ± 50us");
+        assert_eq!(u.unit(), crate::duration::Unit::Microsecond);
+        assert_eq!(u.value(), Duration::from_micros(1_312));
+        assert_eq!(u.uncertainty(), Duration::from_micros(48));
+    }
+}
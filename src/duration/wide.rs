@@ -0,0 +1,540 @@
+use super::{
+    fmt_number, from_nanos, pad, DurationStyle, Part, Parts, Style, Unit, NANOS_PER_SEC, YEAR,
+};
+use std::fmt::Display;
+
+const ATTOS_PER_NANO: u128 = 1_000_000_000;
+
+/// Unit of time for [Wide] values, extending the [Unit] ladder down to attoseconds and up
+/// to exayears.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WideUnit {
+    Attosecond,
+    Femtosecond,
+    Picosecond,
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+    KiloYear,
+    MegaYear,
+    GigaYear,
+    TeraYear,
+    PetaYear,
+    ExaYear,
+}
+
+impl WideUnit {
+    /// All units, from the smallest to the largest.
+    pub const LADDER: [WideUnit; 19] = [
+        WideUnit::Attosecond,
+        WideUnit::Femtosecond,
+        WideUnit::Picosecond,
+        WideUnit::Nanosecond,
+        WideUnit::Microsecond,
+        WideUnit::Millisecond,
+        WideUnit::Second,
+        WideUnit::Minute,
+        WideUnit::Hour,
+        WideUnit::Day,
+        WideUnit::Week,
+        WideUnit::Month,
+        WideUnit::Year,
+        WideUnit::KiloYear,
+        WideUnit::MegaYear,
+        WideUnit::GigaYear,
+        WideUnit::TeraYear,
+        WideUnit::PetaYear,
+        WideUnit::ExaYear,
+    ];
+
+    /// The same unit on the [Unit] ladder, if it is there.
+    pub const fn unit(self) -> Option<Unit> {
+        match self {
+            WideUnit::Nanosecond => Some(Unit::Nanosecond),
+            WideUnit::Microsecond => Some(Unit::Microsecond),
+            WideUnit::Millisecond => Some(Unit::Millisecond),
+            WideUnit::Second => Some(Unit::Second),
+            WideUnit::Minute => Some(Unit::Minute),
+            WideUnit::Hour => Some(Unit::Hour),
+            WideUnit::Day => Some(Unit::Day),
+            WideUnit::Week => Some(Unit::Week),
+            WideUnit::Month => Some(Unit::Month),
+            WideUnit::Year => Some(Unit::Year),
+            WideUnit::KiloYear => Some(Unit::KiloYear),
+            WideUnit::MegaYear => Some(Unit::MegaYear),
+            WideUnit::GigaYear => Some(Unit::GigaYear),
+            _ => None,
+        }
+    }
+
+    /// Length of the unit in seconds.
+    pub fn as_secs_f64(self) -> f64 {
+        match self {
+            WideUnit::Attosecond => 1e-18,
+            WideUnit::Femtosecond => 1e-15,
+            WideUnit::Picosecond => 1e-12,
+            WideUnit::TeraYear => YEAR as f64 * 1e12,
+            WideUnit::PetaYear => YEAR as f64 * 1e15,
+            WideUnit::ExaYear => YEAR as f64 * 1e18,
+            unit => unit.unit().map_or(0.0, |u| u.as_nanos() as f64 / 1e9),
+        }
+    }
+
+    /// Length of the unit in nanoseconds, for units of at least a nanosecond.
+    const fn as_nanos(self) -> Option<u128> {
+        let year = YEAR as u128 * NANOS_PER_SEC;
+        match self {
+            WideUnit::Attosecond | WideUnit::Femtosecond | WideUnit::Picosecond => None,
+            WideUnit::TeraYear => Some(year * 1_000_000_000_000),
+            WideUnit::PetaYear => Some(year * 1_000_000_000_000_000),
+            WideUnit::ExaYear => Some(year * 1_000_000_000_000_000_000),
+            unit => match unit.unit() {
+                Some(u) => Some(u.as_nanos()),
+                None => None,
+            },
+        }
+    }
+
+    /// Short symbol used in formatted output.
+    ///
+    /// # Example
+    /// ```
+    /// use folktime::duration::WideUnit;
+    ///
+    /// assert_eq!(WideUnit::Femtosecond.symbol(), "fs");
+    /// assert_eq!(WideUnit::ExaYear.symbol(), "Ey");
+    /// ```
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            WideUnit::Attosecond => "as",
+            WideUnit::Femtosecond => "fs",
+            WideUnit::Picosecond => "ps",
+            WideUnit::TeraYear => "Ty",
+            WideUnit::PetaYear => "Py",
+            WideUnit::ExaYear => "Ey",
+            unit => match unit.unit() {
+                Some(u) => u.symbol(),
+                None => "",
+            },
+        }
+    }
+
+    /// Name of one unit, e.g. "picosecond".
+    pub const fn name(self) -> &'static str {
+        match self {
+            WideUnit::Attosecond => "attosecond",
+            WideUnit::Femtosecond => "femtosecond",
+            WideUnit::Picosecond => "picosecond",
+            WideUnit::TeraYear => "trillion years",
+            WideUnit::PetaYear => "quadrillion years",
+            WideUnit::ExaYear => "quintillion years",
+            unit => match unit.unit() {
+                Some(u) => u.name(),
+                None => "",
+            },
+        }
+    }
+
+    /// Name of several units, e.g. "picoseconds".
+    pub const fn plural(self) -> &'static str {
+        match self {
+            WideUnit::Attosecond => "attoseconds",
+            WideUnit::Femtosecond => "femtoseconds",
+            WideUnit::Picosecond => "picoseconds",
+            WideUnit::TeraYear => "trillion years",
+            WideUnit::PetaYear => "quadrillion years",
+            WideUnit::ExaYear => "quintillion years",
+            unit => match unit.unit() {
+                Some(u) => u.plural(),
+                None => "",
+            },
+        }
+    }
+
+    /// Indefinite article used before the name of one unit, e.g. "an" for "an attosecond".
+    pub const fn article(self) -> &'static str {
+        match self {
+            WideUnit::Attosecond | WideUnit::Hour => "an",
+            _ => "a",
+        }
+    }
+
+    /// The unit a [Unit] stands for in a duration scaled up by 10^9.
+    pub(crate) fn scaled_down(unit: Unit) -> Self {
+        match unit {
+            Unit::Nanosecond => WideUnit::Attosecond,
+            Unit::Microsecond => WideUnit::Femtosecond,
+            Unit::Millisecond => WideUnit::Picosecond,
+            _ => WideUnit::Nanosecond,
+        }
+    }
+}
+
+impl From<Unit> for WideUnit {
+    fn from(unit: Unit) -> Self {
+        WideUnit::LADDER[unit as usize + 3]
+    }
+}
+
+impl Display for WideUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+fn fmt_scaled_part(part: Part, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    part.fmt_value(f)?;
+    write!(f, "{}", WideUnit::scaled_down(part.unit))
+}
+
+/// Writes the parts of a duration scaled up by 10^9 in the units they stand for, so that
+/// e.g. `312ms` is written as `312ps`.
+pub(crate) fn fmt_scaled(parts: Parts, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fmt_scaled_part(parts.first(), f)?;
+    if let Some(second) = parts.second() {
+        f.write_str(" ")?;
+        fmt_scaled_part(second, f)?;
+    }
+    Ok(())
+}
+
+/// Writes `x` with three significant digits in scientific notation, truncated, e.g. `1.23e21`.
+fn fmt_scientific(x: f64, unit: WideUnit, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let mut exp = x.log10().floor() as i32;
+    let mut mantissa = x / 10f64.powi(exp);
+    // Rounding errors can leave the mantissa just outside [1, 10).
+    if mantissa >= 10.0 {
+        mantissa /= 10.0;
+        exp += 1;
+    } else if mantissa < 1.0 {
+        mantissa *= 10.0;
+        exp -= 1;
+    }
+    let mantissa = (mantissa * 100.0).floor() / 100.0;
+    write!(f, "{mantissa:.2}e{exp}{unit}")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Repr {
+    /// Whole nanoseconds and the attoseconds below them.
+    Exact { nanos: u128, attos: u32 },
+    /// Values out of range of [Repr::Exact].
+    Float(f64),
+}
+
+impl Repr {
+    fn to_duration(self) -> Option<std::time::Duration> {
+        let Repr::Exact { nanos, .. } = self else {
+            return None;
+        };
+        (nanos <= std::time::Duration::MAX.as_nanos()).then(|| from_nanos(nanos))
+    }
+}
+
+/// A duration beyond the range of [std::time::Duration], from attoseconds to exayears.
+///
+/// Values that fit in a [std::time::Duration] are formatted exactly like it, with the
+/// [Style]. Below a nanosecond, the style is applied to the value scaled by 10^9, so it is
+/// shown in picoseconds, femtoseconds or attoseconds. Above the largest [std::time::Duration],
+/// values have three significant digits in teras, petas or exas of years.
+///
+/// Values that are too large or too small for any unit can be written in scientific
+/// notation instead, with [Wide::with_scientific].
+///
+/// # Example
+/// ```
+/// use folktime::Folktime;
+///
+/// assert_eq!(Folktime::wide_secs(2.5e-13).to_string(), "250fs");
+/// assert_eq!(Folktime::wide_secs(1.5).to_string(), "1.50s");
+/// assert_eq!(Folktime::wide_nanos(u128::MAX).to_string(), "10782Ey");
+///
+/// let age = Folktime::wide_secs(3.2e28).with_scientific(true);
+/// assert_eq!(age.to_string(), "1.01e21y");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Wide<S = Style> {
+    repr: Repr,
+    style: S,
+    scientific: bool,
+}
+
+impl Wide {
+    /// A duration of `nanos` nanoseconds.
+    pub fn from_nanos(nanos: u128) -> Self {
+        Self::new(Repr::Exact { nanos, attos: 0 })
+    }
+
+    /// A duration of `attos` attoseconds.
+    pub fn from_attos(attos: u128) -> Self {
+        Self::new(Repr::Exact {
+            nanos: attos / ATTOS_PER_NANO,
+            attos: (attos % ATTOS_PER_NANO) as u32,
+        })
+    }
+
+    /// A duration of `secs` seconds.
+    ///
+    /// Negative values are written with a minus sign, infinite ones as `∞` and NaN as `NaN`.
+    pub fn from_secs_f64(secs: f64) -> Self {
+        let repr = if !secs.is_finite() || secs < 0.0 {
+            Repr::Float(secs)
+        } else if secs < 1.0 {
+            match (secs * 1e18) as u128 {
+                0 if secs > 0.0 => Repr::Float(secs),
+                attos => Repr::Exact {
+                    nanos: attos / ATTOS_PER_NANO,
+                    attos: (attos % ATTOS_PER_NANO) as u32,
+                },
+            }
+        } else if secs * 1e9 < u128::MAX as f64 {
+            Repr::Exact {
+                nanos: (secs * 1e9) as u128,
+                attos: 0,
+            }
+        } else {
+            Repr::Float(secs)
+        };
+        Self::new(repr)
+    }
+
+    fn new(repr: Repr) -> Self {
+        Self {
+            repr,
+            style: Style::default(),
+            scientific: false,
+        }
+    }
+}
+
+impl<S> Wide<S> {
+    /// Set the formatting style, used for values that fit in a [std::time::Duration] and
+    /// for values below a nanosecond.
+    pub fn with_style<T: DurationStyle>(self, style: T) -> Wide<T> {
+        Wide {
+            repr: self.repr,
+            style,
+            scientific: self.scientific,
+        }
+    }
+
+    /// Use scientific notation for values of 1000 exayears or more and for values below an
+    /// attosecond, e.g. `1.23e21y` or `5.00e-20s`.
+    pub fn with_scientific(self, scientific: bool) -> Self {
+        Self { scientific, ..self }
+    }
+
+    /// The duration in seconds.
+    pub fn as_secs_f64(&self) -> f64 {
+        match self.repr {
+            Repr::Exact { nanos, attos } => nanos as f64 / 1e9 + attos as f64 / 1e18,
+            Repr::Float(secs) => secs,
+        }
+    }
+
+    /// The duration as a [std::time::Duration], truncated to nanoseconds, if it fits.
+    pub fn to_duration(&self) -> Option<std::time::Duration> {
+        self.repr.to_duration()
+    }
+}
+
+impl<S: DurationStyle> Wide<S> {
+    fn fmt_large(&self, nanos: u128, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let exa = WideUnit::ExaYear.as_nanos().unwrap_or(u128::MAX);
+        if self.scientific && nanos / 1_000 >= exa {
+            return fmt_scientific(nanos as f64 / 1e9 / YEAR as f64, WideUnit::Year, f);
+        }
+        let (unit, len) = [
+            WideUnit::ExaYear,
+            WideUnit::PetaYear,
+            WideUnit::TeraYear,
+            WideUnit::GigaYear,
+        ]
+        .into_iter()
+        .filter_map(|unit| Some((unit, unit.as_nanos()?)))
+        .find(|(_, len)| *len <= nanos)
+        .unwrap_or((WideUnit::GigaYear, u128::MAX));
+        let whole = nanos / len;
+        let frac_digits = match whole {
+            0..=9 => 2,
+            10..=99 => 1,
+            _ => 0,
+        };
+        let scale = 10u128.pow(frac_digits as u32);
+        let frac = nanos % len * scale / len;
+        fmt_number(whole, frac, frac_digits, f)?;
+        write!(f, "{unit}")
+    }
+
+    fn fmt_repr(&self, repr: Repr, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match repr {
+            Repr::Exact { nanos: 0, attos } if attos > 0 => {
+                self.style.fmt_sub_nanos(attos as u64, f)
+            }
+            Repr::Exact { nanos, .. } => match repr.to_duration() {
+                Some(d) => self.style.fmt(d, f),
+                None => self.fmt_large(nanos, f),
+            },
+            Repr::Float(secs) => self.fmt_float(secs, f),
+        }
+    }
+
+    fn fmt_float(&self, secs: f64, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if secs.is_nan() {
+            f.write_str("NaN")
+        } else if secs < 0.0 {
+            f.write_str("-")?;
+            self.fmt_repr(Wide::from_secs_f64(-secs).repr, f)
+        } else if secs.is_infinite() {
+            f.write_str("∞")
+        } else if secs < 1.0 {
+            // Below an attosecond, written like zero unless scientific.
+            match self.scientific {
+                true => fmt_scientific(secs, WideUnit::Second, f),
+                false => self.style.fmt(std::time::Duration::ZERO, f),
+            }
+        } else {
+            let years = secs / YEAR as f64;
+            match self.scientific {
+                true => fmt_scientific(years, WideUnit::Year, f),
+                false => write!(f, "{:.0}{}", (years / 1e18).floor(), WideUnit::ExaYear),
+            }
+        }
+    }
+}
+
+/// Formatting honors width, alignment and fill like [Duration](super::Duration).
+impl<S: DurationStyle> Display for Wide<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if f.width().is_some() {
+            pad(&Unpadded(self).to_string(), f)
+        } else {
+            self.fmt_repr(self.repr, f)
+        }
+    }
+}
+
+/// Formats a [Wide] ignoring width and alignment, so it can be padded as a whole.
+struct Unpadded<'a, S>(&'a Wide<S>);
+
+impl<S: DurationStyle> Display for Unpadded<'_, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt_repr(self.0.repr, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Folktime;
+
+    fn secs(secs: f64) -> String {
+        Folktime::wide_secs(secs).to_string()
+    }
+
+    #[test]
+    fn ladder() {
+        for unit in Unit::LADDER {
+            assert_eq!(WideUnit::from(unit).unit(), Some(unit));
+            assert_eq!(WideUnit::from(unit).symbol(), unit.symbol());
+        }
+        assert_eq!(WideUnit::Picosecond.unit(), None);
+        assert_eq!(WideUnit::Picosecond.as_secs_f64(), 1e-12);
+        assert_eq!(WideUnit::Hour.as_secs_f64(), 3_600.0);
+    }
+    #[test]
+    fn exact() {
+        for d in [
+            std::time::Duration::ZERO,
+            std::time::Duration::from_nanos(1),
+            std::time::Duration::from_nanos(1_500),
+            std::time::Duration::from_nanos(123_456_789_012),
+            std::time::Duration::MAX,
+        ] {
+            assert_eq!(
+                Folktime::wide_nanos(d.as_nanos()).to_string(),
+                Folktime::duration(d).to_string()
+            );
+        }
+        let w = Folktime::wide_nanos(90_000_000_000).with_style(Style::TwoUnitsWhole);
+        assert_eq!(w.to_string(), "1m 30s");
+        assert_eq!(w.to_duration(), Some(std::time::Duration::from_secs(90)));
+    }
+    #[test]
+    fn sub_nanosecond() {
+        assert_eq!(Wide::from_attos(312_500_000).to_string(), "312ps");
+        assert_eq!(Wide::from_attos(31_250_000).to_string(), "31.2ps");
+        assert_eq!(Wide::from_attos(1_000).to_string(), "1.00fs");
+        assert_eq!(Wide::from_attos(42).to_string(), "42as");
+        assert_eq!(Wide::from_attos(1_500).to_string(), "1.50fs");
+        assert_eq!(Wide::from_attos(1_500_000_000).to_string(), "1ns");
+        let w = Wide::from_attos(312_500_000).with_style(Style::TwoUnitsWhole);
+        assert_eq!(w.to_string(), "312ps 500fs");
+        assert_eq!(secs(2.5e-13), "250fs");
+    }
+    #[test]
+    fn sub_nanosecond_styles() {
+        use crate::duration::{Approx, Fuzzy, Phrase};
+        let w = Wide::from_attos(312_500_000);
+        assert_eq!(w.with_style(Phrase).to_string(), "over 312 picoseconds");
+        assert_eq!(w.with_style(Fuzzy::new()).to_string(), "a few seconds");
+        let approx = Approx::new(Style::OneUnitFrac);
+        assert_eq!(w.with_style(approx).to_string(), "~312ps");
+        assert_eq!(
+            Wide::from_attos(1).with_style(Phrase).to_string(),
+            "an attosecond"
+        );
+    }
+    #[test]
+    fn width() {
+        assert_eq!(
+            format!("[{:>8}]", Wide::from_attos(312_500_000)),
+            "[   312ps]"
+        );
+        assert_eq!(format!("[{:^7}]", Folktime::wide_secs(1.5)), "[ 1.50s ]");
+        assert_eq!(
+            format!("[{:>6}]", Folktime::wide_nanos(u128::MAX)),
+            "[10782Ey]"
+        );
+    }
+    #[test]
+    fn large() {
+        let year = YEAR as u128 * NANOS_PER_SEC;
+        let wide = |years: u128| Folktime::wide_nanos(years * year).to_string();
+        assert_eq!(wide(600_000_000_000), "600Gy");
+        assert_eq!(wide(1_500_000_000_000), "1.50Ty");
+        assert_eq!(wide(25_000_000_000_000_000), "25.0Py");
+        assert_eq!(wide(100_000_000_000_000_000_000), "100Ey");
+        assert_eq!(Folktime::wide_nanos(u128::MAX).to_string(), "10782Ey");
+        assert_eq!(secs(1e20 * YEAR as f64), "100Ey");
+    }
+    #[test]
+    fn scientific() {
+        let sci = |secs: f64| Folktime::wide_secs(secs).with_scientific(true).to_string();
+        assert_eq!(sci(1e21 * YEAR as f64), "1.00e21y");
+        assert_eq!(sci(3.2e28), "1.01e21y");
+        assert_eq!(sci(1e40), "3.16e32y");
+        assert_eq!(sci(5e-20), "5.00e-20s");
+        assert_eq!(sci(1e20 * YEAR as f64), "100Ey");
+        assert_eq!(sci(1.5), "1.50s");
+        assert_eq!(secs(5e-20), "0.00s");
+        assert_eq!(secs(5e-20), secs(0.0));
+        assert_eq!(secs(1e40), "316875355494539Ey");
+    }
+    #[test]
+    fn special() {
+        assert_eq!(secs(f64::NAN), "NaN");
+        assert_eq!(secs(f64::INFINITY), "∞");
+        assert_eq!(secs(-1.5), "-1.50s");
+        assert_eq!(secs(-2.5e-13), "-250fs");
+        assert_eq!(Folktime::wide_secs(-2.0).as_secs_f64(), -2.0);
+        assert_eq!(Folktime::wide_secs(-2.0).to_duration(), None);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::duration::{fmt_quotient, Duration, DurationStyle, Style, NANOS_PER_SEC};
+use std::fmt::Display;
+
+/// Time available for a frame, e.g. for 60 frames per second.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::frame::FrameBudget;
+///
+/// let budget = FrameBudget::fps(60);
+/// let ok = budget.frame(Duration::from_micros(4_200));
+/// let slow = budget.frame(Duration::from_micros(20_100));
+///
+/// assert_eq!(ok.to_string(), "4.20ms / 16.6ms (25%) 238fps");
+/// assert_eq!(slow.to_string(), "20.1ms / 16.6ms (120%, 3.43ms over) 49.7fps");
+/// assert!(slow.is_over());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameBudget(std::time::Duration);
+
+impl FrameBudget {
+    /// A budget of `budget` per frame.
+    pub const fn new(budget: std::time::Duration) -> Self {
+        Self(budget)
+    }
+
+    /// The budget per frame at `fps` frames per second.
+    ///
+    /// Above 10^9 frames per second, the budget is clamped to one nanosecond.
+    ///
+    /// # Panics
+    /// If `fps` is zero.
+    pub const fn fps(fps: u32) -> Self {
+        let nanos = match NANOS_PER_SEC as u64 / fps as u64 {
+            0 => 1,
+            nanos => nanos,
+        };
+        Self(std::time::Duration::from_nanos(nanos))
+    }
+
+    pub const fn budget(&self) -> std::time::Duration {
+        self.0
+    }
+
+    /// A frame that took `time`, measured against the budget.
+    pub fn frame(&self, time: std::time::Duration) -> Frame {
+        Frame {
+            time: Duration::new(time),
+            budget: self.0,
+        }
+    }
+}
+
+/// The time of a frame against a [FrameBudget], formatted as the time used, the budget,
+/// the percentage used and the frames per second the time allows, e.g.
+/// `4.20ms / 16.6ms (25%) 238fps`.
+///
+/// Over budget, the excess is shown as well, e.g. `20.1ms / 16.6ms (120%, 3.43ms over) 49.7fps`.
+pub struct Frame<S = Style> {
+    time: Duration<S>,
+    budget: std::time::Duration,
+}
+
+impl<S> Frame<S> {
+    /// Set the formatting style of the times.
+    pub fn with_style<T: DurationStyle>(self, style: T) -> Frame<T> {
+        Frame {
+            time: self.time.with_style(style),
+            budget: self.budget,
+        }
+    }
+
+    pub fn time(&self) -> std::time::Duration {
+        self.time.0
+    }
+
+    pub fn budget(&self) -> std::time::Duration {
+        self.budget
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.time.0 > self.budget
+    }
+
+    /// Time over the budget, zero if within.
+    pub fn over(&self) -> std::time::Duration {
+        self.time.0.saturating_sub(self.budget)
+    }
+
+    /// Percentage of the budget used, truncated.
+    pub fn percent(&self) -> u128 {
+        match self.budget.as_nanos() {
+            0 => 0,
+            budget => self.time.0.as_nanos() * 100 / budget,
+        }
+    }
+
+    /// Frames per second at this frame time.
+    pub fn fps(&self) -> f64 {
+        1.0 / self.time.0.as_secs_f64()
+    }
+}
+
+impl<S: DurationStyle> Display for Frame<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let style = &self.time.1;
+        write!(
+            f,
+            "{} / {} ({}%",
+            self.time,
+            Duration(self.budget, style),
+            self.percent()
+        )?;
+        if self.is_over() {
+            write!(f, ", {} over", Duration(self.over(), style))?;
+        }
+        f.write_str(") ")?;
+        match self.time.0.as_nanos() {
+            0 => f.write_str("∞")?,
+            nanos => fmt_quotient(NANOS_PER_SEC, nanos, f)?,
+        }
+        f.write_str("fps")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn budgets() {
+        assert_eq!(
+            FrameBudget::fps(60).budget(),
+            Duration::from_nanos(16_666_666)
+        );
+        assert_eq!(FrameBudget::fps(20).budget(), Duration::from_millis(50));
+        let budget = FrameBudget::new(Duration::from_millis(10));
+        assert_eq!(budget.budget(), Duration::from_millis(10));
+        assert_eq!(FrameBudget::fps(u32::MAX).budget(), Duration::from_nanos(1));
+        let frame = FrameBudget::fps(u32::MAX).frame(Duration::from_nanos(1));
+        assert_eq!(frame.to_string(), "1ns / 1ns (100%) 1000000000fps");
+    }
+    #[test]
+    fn slow_frames() {
+        let frame = FrameBudget::fps(60).frame(Duration::from_secs(3));
+        assert_eq!(
+            frame.to_string(),
+            "3.00s / 16.6ms (18000%, 2.98s over) 0.333fps"
+        );
+    }
+    #[test]
+    fn within_budget() {
+        let frame = FrameBudget::fps(144).frame(Duration::from_micros(6_944));
+        assert!(!frame.is_over());
+        assert_eq!(frame.over(), Duration::ZERO);
+        assert_eq!(frame.percent(), 99);
+        assert_eq!(frame.to_string(), "6.94ms / 6.94ms (99%) 144fps");
+    }
+    #[test]
+    fn over_budget() {
+        let frame = FrameBudget::fps(60).frame(Duration::from_millis(50));
+        assert!(frame.is_over());
+        assert_eq!(frame.percent(), 300);
+        assert_eq!(
+            frame.to_string(),
+            "50.0ms / 16.6ms (300%, 33.3ms over) 20.0fps"
+        );
+    }
+    #[test]
+    fn style() {
+        let frame = FrameBudget::fps(30)
+            .frame(Duration::from_micros(12_345))
+            .with_style(crate::duration::Style::OneUnitWhole);
+        assert_eq!(frame.to_string(), "12ms / 33ms (37%) 81.0fps");
+    }
+    #[test]
+    fn zero() {
+        let frame = FrameBudget::new(Duration::ZERO).frame(Duration::ZERO);
+        assert_eq!(frame.to_string(), "0.00s / 0.00s (0%) ∞fps");
+    }
+}
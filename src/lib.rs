@@ -1,8 +1,36 @@
 //! A library for formatting time-related values in a human-friendly way.
 
+pub mod clock;
+pub mod compare;
+pub mod deadline;
 pub mod duration;
+pub mod frame;
+#[cfg(feature = "log")]
+pub mod logging;
+pub mod progress;
+pub mod range;
+pub mod rate;
+pub mod relative;
+pub mod stats;
+pub mod stopwatch;
+pub mod ticks;
+#[cfg(feature = "tracing")]
+pub mod trace;
 
-use duration::Duration;
+/// The examples in the README, compiled and run as doc tests. Some use the `log` and
+/// `tracing` features, so they only run with all features enabled.
+#[cfg(all(doctest, feature = "log", feature = "tracing"))]
+#[doc = include_str!("../README.md")]
+pub struct ReadmeDoctests;
+
+use compare::Comparison;
+use deadline::Deadline;
+use duration::{Duration, Optional, Uncertain, Wide};
+use range::Range;
+use rate::Rate;
+use relative::Relative;
+use std::time::SystemTime;
+use ticks::Ticks;
 
 /// A library for formatting time-related values in a human-friendly way.
 ///
@@ -64,4 +92,155 @@ impl Folktime {
     pub const fn duration(d: std::time::Duration) -> Duration {
         Duration(d, duration::Style::OneUnitFrac)
     }
+
+    /// Used for formatting a duration given in nanoseconds that may not fit in a
+    /// [std::time::Duration], up to exayears.
+    ///
+    /// # Example
+    /// ```rust
+    /// use folktime::Folktime;
+    ///
+    /// let d = Folktime::wide_nanos(u128::MAX);
+    /// assert_eq!(format!("{}", d), "10782Ey");
+    /// ```
+    pub fn wide_nanos(nanos: u128) -> Wide {
+        Wide::from_nanos(nanos)
+    }
+
+    /// Used for formatting a duration given in seconds, from attoseconds to exayears.
+    ///
+    /// # Example
+    /// ```rust
+    /// use folktime::Folktime;
+    ///
+    /// let d = Folktime::wide_secs(2.5e-13);
+    /// assert_eq!(format!("{}", d), "250fs");
+    /// ```
+    pub fn wide_secs(secs: f64) -> Wide {
+        Wide::from_secs_f64(secs)
+    }
+
+    /// Used for formatting a [std::time::Duration] that may be missing, shown as `—` when
+    /// it is.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use folktime::Folktime;
+    ///
+    /// assert_eq!(format!("{}", Folktime::optional(Some(Duration::from_secs(5)))), "5.00s");
+    /// assert_eq!(format!("{}", Folktime::optional(None)), "—");
+    /// ```
+    pub fn optional(d: Option<std::time::Duration>) -> Optional {
+        Optional::new(d)
+    }
+
+    /// Used for formatting a [SystemTime] relative to another one, e.g. `3.20m ago`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::{Duration, SystemTime};
+    /// use folktime::Folktime;
+    ///
+    /// let now = SystemTime::now();
+    /// let d = Folktime::relative(now - Duration::from_secs(5), now);
+    /// assert_eq!(format!("{}", d), "5.00s ago");
+    /// ```
+    pub fn relative(time: SystemTime, now: SystemTime) -> Relative {
+        Relative::new(time, now)
+    }
+
+    /// Used for formatting a [SystemTime] relative to the current time.
+    pub fn since(time: SystemTime) -> Relative {
+        Relative::new(time, SystemTime::now())
+    }
+
+    /// Used for formatting a [SystemTime] deadline relative to another time, e.g.
+    /// `due in 3.20h` or `overdue by 12.0m`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::{Duration, SystemTime};
+    /// use folktime::Folktime;
+    ///
+    /// let now = SystemTime::now();
+    /// let d = Folktime::deadline(now - Duration::from_secs(720), now);
+    /// assert_eq!(format!("{}", d), "overdue by 12.0m");
+    /// ```
+    pub fn deadline(deadline: SystemTime, now: SystemTime) -> Deadline {
+        Deadline::from_system_time(deadline, now)
+    }
+
+    /// Used for comparing a candidate [std::time::Duration] to a baseline, e.g.
+    /// `12.3ms → 15.1ms (+2.80ms, 1.22× slower)`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use folktime::Folktime;
+    ///
+    /// let c = Folktime::compare(Duration::from_millis(150), Duration::from_millis(100));
+    /// assert_eq!(format!("{}", c), "150ms → 100ms (-50.0ms, 1.50× faster)");
+    /// ```
+    pub fn compare(baseline: std::time::Duration, candidate: std::time::Duration) -> Comparison {
+        Comparison::new(baseline, candidate)
+    }
+
+    /// Used for formatting a range of durations, e.g. `2–3h` or `5m to 3d`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use folktime::Folktime;
+    ///
+    /// let r = Folktime::range(Duration::from_secs(7200), Duration::from_secs(10800));
+    /// assert_eq!(format!("{}", r), "2–3h");
+    /// ```
+    pub fn range(a: std::time::Duration, b: std::time::Duration) -> Range {
+        Range::new(a, b)
+    }
+
+    /// Used for formatting a rate given by its period, e.g. `60.0Hz`, `3.00/h` or
+    /// `every 20.0m`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use folktime::Folktime;
+    ///
+    /// let r = Folktime::rate(Duration::from_micros(400));
+    /// assert_eq!(format!("{}", r), "2.50kHz");
+    /// ```
+    pub fn rate(period: std::time::Duration) -> Rate {
+        Rate::new(period)
+    }
+
+    /// Used for formatting a count of ticks or cycles at a clock rate in ticks per second,
+    /// e.g. CPU cycles at 3.2GHz.
+    ///
+    /// # Example
+    /// ```rust
+    /// use folktime::Folktime;
+    ///
+    /// let t = Folktime::ticks(1_234, 20);
+    /// assert_eq!(format!("{}", t), "1.02m");
+    /// ```
+    pub fn ticks(count: u64, hz: u64) -> Ticks {
+        Ticks::new(count, hz)
+    }
+
+    /// Used for formatting a measured [std::time::Duration] with its uncertainty, showing
+    /// only the digits the uncertainty allows.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use folktime::Folktime;
+    ///
+    /// let d = Folktime::uncertain(Duration::from_micros(1_312), Duration::from_micros(48));
+    /// assert_eq!(format!("{}", d), "1.31 ± 0.05ms");
+    /// ```
+    pub fn uncertain(value: std::time::Duration, uncertainty: std::time::Duration) -> Uncertain {
+        Uncertain::new(value, uncertainty)
+    }
 }
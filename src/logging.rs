@@ -0,0 +1,282 @@
+//! Timing helpers for the [log] crate, formatted in a human-friendly way.
+//!
+//! Requires the `log` feature.
+
+use crate::duration::{Duration, DurationStyle, Style};
+use log::{Level, Log, Metadata, Record};
+use std::borrow::Cow;
+use std::time::Instant;
+
+/// Start a [Timer] that logs with the calling module as the target, like the macros of
+/// the [log] crate.
+///
+/// # Example
+/// ```
+/// fn load_config() {
+///     let _timer = folktime::timer!("load config");
+///     // ...
+/// } // logs "load config took 12.3ms" with the target of `load_config`'s module
+/// # load_config();
+/// ```
+#[macro_export]
+macro_rules! timer {
+    ($label:expr) => {
+        $crate::logging::Timer::new(::std::module_path!(), $label)
+    };
+}
+
+/// Logs how long it lived when dropped, e.g. `load config took 12.3ms`.
+///
+/// The record has a target like those of the [log] macros, so it can be filtered by
+/// module. [timer!](crate::timer!) creates a timer with the calling module as the target.
+///
+/// # Example
+/// ```
+/// use folktime::logging::Timer;
+///
+/// fn load_config() {
+///     let _timer = Timer::new(module_path!(), "load config").with_level(log::Level::Debug);
+///     // ...
+/// } // logs "load config took 12.3ms"
+/// # load_config();
+/// ```
+pub struct Timer<S: DurationStyle = Style> {
+    label: Cow<'static, str>,
+    level: Level,
+    target: &'static str,
+    /// `None` once the timer was replaced by one with another style, so it doesn't log.
+    style: Option<S>,
+    start: Instant,
+}
+
+impl Timer {
+    /// Start timing, logging `label` with `target` at [Level::Info] when dropped.
+    pub fn new(target: &'static str, label: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            label: label.into(),
+            level: Level::Info,
+            target,
+            style: Some(Style::OneUnitFrac),
+            start: Instant::now(),
+        }
+    }
+}
+
+impl<S: DurationStyle> Timer<S> {
+    /// Set the level of the record.
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Set the target of the record.
+    pub fn with_target(mut self, target: &'static str) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Set the formatting style of the elapsed time.
+    pub fn with_style<T: DurationStyle>(mut self, style: T) -> Timer<T> {
+        self.style = None;
+        Timer {
+            label: std::mem::take(&mut self.label),
+            level: self.level,
+            target: self.target,
+            style: Some(style),
+            start: self.start,
+        }
+    }
+
+    /// Time since the timer was started.
+    pub fn elapsed(&self) -> std::time::Duration {
+        self.start.elapsed()
+    }
+}
+
+impl<S: DurationStyle> Drop for Timer<S> {
+    fn drop(&mut self) {
+        let Some(style) = &self.style else { return };
+        let elapsed = Duration(self.elapsed(), style);
+        log::log!(target: self.target, self.level, "{} took {elapsed}", self.label);
+    }
+}
+
+/// A [Log] that prefixes every message with the time since the program started,
+/// e.g. `[ 1.02s] listening`, and passes it on to another logger.
+///
+/// The time is right-aligned to 6 characters, so messages line up.
+///
+/// # Example
+/// ```no_run
+/// use folktime::logging::Elapsed;
+///
+/// # struct Logger;
+/// # impl log::Log for Logger {
+/// #     fn enabled(&self, _: &log::Metadata) -> bool { true }
+/// #     fn log(&self, _: &log::Record) {}
+/// #     fn flush(&self) {}
+/// # }
+/// log::set_boxed_logger(Box::new(Elapsed::new(Logger))).unwrap();
+/// log::set_max_level(log::LevelFilter::Info);
+/// ```
+pub struct Elapsed<L, S = Style> {
+    inner: L,
+    style: S,
+    start: Instant,
+}
+
+impl<L: Log> Elapsed<L> {
+    /// Wrap `inner`, counting from now. Create it first thing in `main`.
+    pub fn new(inner: L) -> Self {
+        Self {
+            inner,
+            style: Style::OneUnitFrac,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl<L, S> Elapsed<L, S> {
+    /// Count from `start` instead of from the creation of the logger.
+    pub fn with_start(self, start: Instant) -> Self {
+        Self { start, ..self }
+    }
+
+    /// Set the formatting style of the elapsed time.
+    pub fn with_style<T: DurationStyle>(self, style: T) -> Elapsed<L, T> {
+        Elapsed {
+            inner: self.inner,
+            style,
+            start: self.start,
+        }
+    }
+}
+
+impl<L, S> Log for Elapsed<L, S>
+where
+    L: Log,
+    S: DurationStyle + Send + Sync,
+{
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.inner.enabled(record.metadata()) {
+            return;
+        }
+        let elapsed = Duration(self.start.elapsed(), &self.style);
+        self.inner.log(
+            &Record::builder()
+                .metadata(record.metadata().clone())
+                .module_path(record.module_path())
+                .file(record.file())
+                .line(record.line())
+                .args(format_args!("[{elapsed:>6}] {}", record.args()))
+                .build(),
+        );
+    }
+
+    fn flush(&self) {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Keeps the messages it is given.
+    #[derive(Default)]
+    struct Collect(Mutex<Vec<(Level, String, String)>>);
+
+    impl Log for Collect {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+        fn log(&self, record: &Record) {
+            self.0.lock().unwrap().push((
+                record.level(),
+                record.target().to_string(),
+                record.args().to_string(),
+            ));
+        }
+        fn flush(&self) {}
+    }
+
+    static LOGGER: Collect = Collect(Mutex::new(vec![]));
+
+    /// Messages logged through the global logger with `label`.
+    fn logged(label: &str) -> Vec<(Level, String, String)> {
+        let messages = LOGGER.0.lock().unwrap();
+        let messages = messages.iter().filter(|(_, _, m)| m.starts_with(label));
+        messages.cloned().collect()
+    }
+
+    #[test]
+    fn timer() {
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(log::LevelFilter::Trace);
+        {
+            let _timer = Timer::new("app::sleep", "sleep");
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        let messages = logged("sleep");
+        assert_eq!(messages.len(), 1);
+        let (level, target, message) = &messages[0];
+        assert_eq!((*level, target.as_str()), (Level::Info, "app::sleep"));
+        let took = message.strip_prefix("sleep took ").unwrap();
+        assert!(crate::duration::parse(took).unwrap() >= std::time::Duration::from_millis(20));
+    }
+    #[test]
+    fn timer_macro() {
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(log::LevelFilter::Trace);
+        drop(crate::timer!("macro"));
+        let messages = logged("macro");
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].1, "folktime::logging::tests");
+    }
+    #[test]
+    fn timer_options() {
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(log::LevelFilter::Trace);
+        drop(
+            Timer::new("ignored", format!("{} {}", "custom", 1))
+                .with_level(Level::Debug)
+                .with_target("app")
+                .with_style(Style::OneUnitWhole),
+        );
+        let messages = logged("custom 1");
+        assert_eq!(messages.len(), 1);
+        let (level, target, message) = &messages[0];
+        assert_eq!((*level, target.as_str()), (Level::Debug, "app"));
+        let took = message.strip_prefix("custom 1 took ").unwrap();
+        assert!(crate::duration::parse(took).is_ok(), "{message}");
+        assert!(!took.contains('.'), "{message}");
+    }
+    #[test]
+    fn elapsed() {
+        let ago = std::time::Duration::from_millis(1_500);
+        let start = Instant::now().checked_sub(ago).unwrap_or_else(Instant::now);
+        let before = start.elapsed();
+        let logger = Elapsed::new(Collect::default()).with_start(start);
+        logger.log(&Record::builder().args(format_args!("hello")).build());
+        let logger = logger.with_style(Style::TwoUnitsWhole);
+        logger.log(&Record::builder().args(format_args!("again")).build());
+        let after = start.elapsed();
+        let messages = logger.inner.0.lock().unwrap();
+        for ((_, _, message), text) in messages.iter().zip(["hello", "again"]) {
+            let (prefix, rest) = message.split_once(']').unwrap();
+            assert_eq!(rest, format!(" {text}"));
+            let time = prefix.strip_prefix('[').unwrap();
+            assert!(time.chars().count() >= 6, "{message}");
+            let time = crate::duration::parse(time.trim_start()).unwrap();
+            // Shown truncated to three significant digits or to whole milliseconds.
+            let resolution = std::time::Duration::from_millis(10);
+            assert!(time <= after && time + resolution >= before, "{message}");
+        }
+        assert!(!messages[1].2.contains('.'), "{}", messages[1].2);
+    }
+}
@@ -0,0 +1,379 @@
+use crate::clock::{Clock, SystemClock};
+use crate::duration::{fmt_quotient, Duration, DurationStyle, Style, NANOS_PER_SEC};
+use std::fmt::Display;
+use std::time::Instant;
+
+/// Estimates the rate and the remaining time of a job from its progress updates.
+///
+/// The rate is an exponential moving average over a time window, so it follows changes in
+/// speed without jumping on every update. Between updates the remaining time counts down.
+/// When no progress is made for longer than the stall timeout, the rate and the remaining
+/// time are unknown.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::clock::ManualClock;
+/// use folktime::progress::Estimator;
+///
+/// let clock = ManualClock::new();
+/// let mut eta = Estimator::with_clock(&clock);
+/// for done in 1..=42 {
+///     clock.advance(Duration::from_millis(4_572));
+///     eta.update(done * 100, 10_000);
+/// }
+///
+/// assert_eq!(
+///     eta.progress().to_string(),
+///     "42% — 3.20m elapsed, ~4.41m remaining, 21.8 items/s"
+/// );
+/// ```
+pub struct Estimator<C = SystemClock> {
+    clock: C,
+    start: Instant,
+    done: u64,
+    total: u64,
+    /// Items per second.
+    rate: Option<f64>,
+    last_update: Instant,
+    last_progress: Instant,
+    window: std::time::Duration,
+    stall_timeout: std::time::Duration,
+}
+
+impl Estimator {
+    /// Start estimating now, using the real clock.
+    pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl Default for Estimator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Clock> Estimator<C> {
+    /// Start estimating now, using `clock`.
+    pub fn with_clock(clock: C) -> Self {
+        let now = clock.now();
+        Self {
+            clock,
+            start: now,
+            done: 0,
+            total: 0,
+            rate: None,
+            last_update: now,
+            last_progress: now,
+            window: std::time::Duration::from_secs(10),
+            stall_timeout: std::time::Duration::from_secs(10),
+        }
+    }
+
+    /// Set how far back the rate looks, 10 seconds by default.
+    ///
+    /// A longer window gives steadier estimates that are slower to follow changes in speed.
+    pub fn with_window(self, window: std::time::Duration) -> Self {
+        Self { window, ..self }
+    }
+
+    /// Set how long without progress makes the estimates unknown, 10 seconds by default.
+    pub fn with_stall_timeout(self, stall_timeout: std::time::Duration) -> Self {
+        Self {
+            stall_timeout,
+            ..self
+        }
+    }
+
+    /// Record that `done` out of `total` items are done. A `total` of zero means it is
+    /// unknown.
+    pub fn update(&mut self, done: u64, total: u64) {
+        let now = self.clock.now();
+        self.update_at(done, total, now);
+    }
+
+    /// Record that `done` out of `total` items were done at `time`.
+    ///
+    /// Updates older than the previous one are ignored, and so is a `done` lower than
+    /// before, so progress never goes back.
+    pub fn update_at(&mut self, done: u64, total: u64, time: Instant) {
+        let Some(dt) = time.checked_duration_since(self.last_update) else {
+            return;
+        };
+        self.total = total;
+        if done <= self.done || dt.is_zero() {
+            self.done = self.done.max(done);
+            return;
+        }
+        let rate = done.saturating_sub(self.done) as f64 / dt.as_secs_f64();
+        self.rate = Some(match self.rate {
+            None => rate,
+            Some(average) => {
+                let weight = 1.0 - (-dt.as_secs_f64() / self.window.as_secs_f64()).exp();
+                average + weight * (rate - average)
+            }
+        });
+        self.done = done;
+        self.last_update = time;
+        self.last_progress = time;
+    }
+
+    /// Time since the estimator was created.
+    pub fn elapsed(&self) -> std::time::Duration {
+        self.clock.now().saturating_duration_since(self.start)
+    }
+
+    /// Whether no progress was made for longer than the stall timeout.
+    pub fn is_stalled(&self) -> bool {
+        self.clock
+            .now()
+            .saturating_duration_since(self.last_progress)
+            > self.stall_timeout
+    }
+
+    /// Smoothed items per second, unknown before the first progress and when stalled.
+    pub fn rate(&self) -> Option<f64> {
+        self.rate.filter(|rate| *rate > 0.0 && !self.is_stalled())
+    }
+
+    /// Estimated time until all items are done, unknown when the total is.
+    pub fn remaining(&self) -> Option<std::time::Duration> {
+        if self.total == 0 {
+            return None;
+        }
+        if self.done >= self.total {
+            return Some(std::time::Duration::ZERO);
+        }
+        let left = self.total.saturating_sub(self.done) as f64 / self.rate()?;
+        let since_update = self.clock.now().saturating_duration_since(self.last_update);
+        let left = std::time::Duration::try_from_secs_f64(left).ok()?;
+        Some(left.saturating_sub(since_update))
+    }
+
+    /// Done items as a fraction of the total, from 0 to 1.
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            (self.done as f64 / self.total as f64).min(1.0)
+        }
+    }
+
+    /// The current estimates, to be displayed.
+    pub fn progress(&self) -> Progress {
+        Progress {
+            fraction: self.fraction(),
+            elapsed: Duration::new(self.elapsed()),
+            remaining: self.remaining(),
+            rate: self.rate(),
+            unit: "items",
+        }
+    }
+}
+
+/// Estimates of an [Estimator], formatted as e.g.
+/// `42% — 3.20m elapsed, ~4.50m remaining, 1.20k items/s`.
+///
+/// Unknown estimates are shown as `remaining unknown` and `rate unknown`.
+pub struct Progress<S = Style> {
+    fraction: f64,
+    elapsed: Duration<S>,
+    remaining: Option<std::time::Duration>,
+    rate: Option<f64>,
+    unit: &'static str,
+}
+
+impl<S> Progress<S> {
+    /// Set the formatting style of the times.
+    pub fn with_style<T: DurationStyle>(self, style: T) -> Progress<T> {
+        Progress {
+            fraction: self.fraction,
+            elapsed: self.elapsed.with_style(style),
+            remaining: self.remaining,
+            rate: self.rate,
+            unit: self.unit,
+        }
+    }
+
+    /// Set what is being counted, `items` by default.
+    pub fn with_unit(self, unit: &'static str) -> Self {
+        Self { unit, ..self }
+    }
+}
+
+/// Writes `value` with 3 significant digits and a metric prefix, truncated, e.g. `1.20k`.
+fn fmt_rate(value: f64, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    // Rounded to billionths, so that e.g. 54.7 isn't truncated to 54.6.
+    let num = (value * NANOS_PER_SEC as f64).round() as u128;
+    let (scale, prefix) = [(12, "T"), (9, "G"), (6, "M"), (3, "k")]
+        .into_iter()
+        .map(|(e, prefix)| (NANOS_PER_SEC * 10u128.pow(e), prefix))
+        .find(|(scale, _)| num >= *scale)
+        .unwrap_or((NANOS_PER_SEC, ""));
+    fmt_quotient(num, scale, f)?;
+    write!(f, "{prefix}")
+}
+
+impl<S: DurationStyle> Display for Progress<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let percent = (self.fraction * 100.0).floor();
+        write!(f, "{percent}% — {} elapsed, ", self.elapsed)?;
+        match self.remaining {
+            Some(d) => write!(f, "~{} remaining, ", Duration(d, &self.elapsed.1))?,
+            None => write!(f, "remaining unknown, ")?,
+        }
+        match self.rate {
+            Some(rate) => {
+                fmt_rate(rate, f)?;
+                write!(f, " {}/s", self.unit)
+            }
+            None => write!(f, "rate unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use std::time::Duration;
+
+    #[test]
+    fn steady() {
+        let clock = ManualClock::new();
+        let mut eta = Estimator::with_clock(&clock);
+        assert_eq!(eta.rate(), None);
+        assert_eq!(eta.remaining(), None);
+        for done in 1..=10 {
+            clock.advance(Duration::from_secs(1));
+            eta.update(done * 100, 2_000);
+        }
+        assert_eq!(eta.rate(), Some(100.0));
+        assert_eq!(eta.remaining(), Some(Duration::from_secs(10)));
+        assert_eq!(eta.fraction(), 0.5);
+        clock.advance(Duration::from_millis(400));
+        assert_eq!(eta.remaining(), Some(Duration::from_millis(9_600)));
+        assert_eq!(
+            eta.progress().with_unit("rows").to_string(),
+            "50% — 10.4s elapsed, ~9.60s remaining, 100 rows/s"
+        );
+    }
+    #[test]
+    fn smoothing() {
+        let clock = ManualClock::new();
+        let mut eta = Estimator::with_clock(&clock);
+        for done in 1..=10 {
+            clock.advance(Duration::from_secs(1));
+            eta.update(done * 100, 10_000);
+        }
+        // A burst moves the rate only part of the way.
+        clock.advance(Duration::from_secs(1));
+        eta.update(2_000, 10_000);
+        let rate = eta.rate().unwrap();
+        assert!(rate > 100.0 && rate < 200.0, "{rate}");
+        let remaining = eta.remaining().unwrap();
+        assert!(remaining > Duration::from_secs(40) && remaining < Duration::from_secs(80));
+    }
+    #[test]
+    fn stalls() {
+        let clock = ManualClock::new();
+        let mut eta = Estimator::with_clock(&clock).with_stall_timeout(Duration::from_secs(5));
+        clock.advance(Duration::from_secs(1));
+        eta.update(10, 100);
+        clock.advance(Duration::from_secs(3));
+        eta.update(10, 100);
+        assert!(!eta.is_stalled());
+        assert!(eta.rate().is_some());
+        clock.advance(Duration::from_secs(3));
+        assert!(eta.is_stalled());
+        assert_eq!(eta.rate(), None);
+        assert_eq!(
+            eta.progress().to_string(),
+            "10% — 7.00s elapsed, remaining unknown, rate unknown"
+        );
+        clock.advance(Duration::from_secs(1));
+        eta.update(20, 100);
+        assert!(!eta.is_stalled());
+        assert!(eta.remaining().is_some());
+    }
+    #[test]
+    fn unknown_total() {
+        let clock = ManualClock::new();
+        let mut eta = Estimator::with_clock(&clock);
+        for done in 1..=5 {
+            clock.advance(Duration::from_secs(1));
+            eta.update(done * 100, 0);
+        }
+        assert_eq!(eta.rate(), Some(100.0));
+        assert_eq!(eta.remaining(), None);
+        assert_eq!(
+            eta.progress().to_string(),
+            "0% — 5.00s elapsed, remaining unknown, 100 items/s"
+        );
+    }
+    #[test]
+    fn finished() {
+        let clock = ManualClock::new();
+        let mut eta = Estimator::with_clock(&clock);
+        clock.advance(Duration::from_secs(90));
+        eta.update(5, 5);
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(eta.remaining(), Some(Duration::ZERO));
+        assert_eq!(
+            eta.progress()
+                .with_style(crate::duration::Style::TwoUnitsWhole)
+                .to_string(),
+            "100% — 2m 30s elapsed, ~0s 0ms remaining, rate unknown"
+        );
+    }
+    #[test]
+    fn old_updates() {
+        let clock = ManualClock::new();
+        let start = clock.now();
+        let mut eta = Estimator::with_clock(&clock);
+        clock.advance(Duration::from_secs(2));
+        eta.update(10, 100);
+        eta.update_at(50, 100, start + Duration::from_secs(1));
+        assert_eq!(eta.rate(), Some(5.0));
+    }
+    #[test]
+    fn decreasing() {
+        let clock = ManualClock::new();
+        let mut eta = Estimator::with_clock(&clock);
+        clock.advance(Duration::from_secs(1));
+        eta.update(10, 100);
+        clock.advance(Duration::from_secs(1));
+        eta.update(5, 100);
+        assert_eq!(eta.fraction(), 0.1);
+        assert_eq!(eta.rate(), Some(10.0));
+        clock.advance(Duration::from_secs(1));
+        eta.update(40, 100);
+        assert_eq!(eta.fraction(), 0.4);
+        assert!(eta.rate().unwrap() > 10.0);
+    }
+    #[test]
+    fn rates() {
+        struct Rate(f64);
+        impl Display for Rate {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                fmt_rate(self.0, f)
+            }
+        }
+        let cases = [
+            (0.5, "0.500"),
+            (9.999, "9.99"),
+            (54.7, "54.7"),
+            (999.9, "999"),
+            (1_234.0, "1.23k"),
+            (1_239.9, "1.23k"),
+            (12_345.0, "12.3k"),
+            (5_600_000.0, "5.60M"),
+            (7e9, "7.00G"),
+            (2e13, "20.0T"),
+        ];
+        for (rate, expected) in cases {
+            assert_eq!(Rate(rate).to_string(), expected);
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use crate::duration::{Duration, DurationStyle, Part, Parts, Style};
+use std::fmt::Display;
+
+/// A range of durations, e.g. an estimate, formatted as `2–3h` or `5m to 3d`.
+///
+/// Both ends are written in the unit of the larger end, with the unit written once and
+/// trailing zeros dropped. The next smaller unit is used instead when it shows both ends
+/// exactly in at most three digits and the larger one does not, so `50–70m` rather than
+/// `0.83–1.16h`. When the smaller end would be 0 in the shared unit, the ends are orders
+/// of magnitude apart and each keeps its own unit. Ends that look the same are written
+/// once.
+///
+/// Styles that show more than one part, or write something other than their parts such
+/// as words, always write both ends in full.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::Folktime;
+///
+/// let mins = |m: u64| Duration::from_secs(m * 60);
+/// assert_eq!(Folktime::range(mins(120), mins(180)).to_string(), "2–3h");
+/// assert_eq!(Folktime::range(mins(90), mins(120)).to_string(), "1.5–2h");
+/// assert_eq!(Folktime::range(mins(50), mins(70)).to_string(), "50–70m");
+/// assert_eq!(Folktime::range(mins(5), mins(3 * 24 * 60)).to_string(), "5m to 3d");
+/// ```
+pub struct Range<S = Style> {
+    low: Duration<S>,
+    high: std::time::Duration,
+    separator: &'static str,
+    word: &'static str,
+}
+
+impl Range {
+    /// A range between `a` and `b`, in any order.
+    pub fn new(a: std::time::Duration, b: std::time::Duration) -> Self {
+        Self {
+            low: Duration::new(a.min(b)),
+            high: a.max(b),
+            separator: "–",
+            word: "to",
+        }
+    }
+}
+
+impl<S> Range<S> {
+    /// Set the formatting style of the ends.
+    pub fn with_style<T: DurationStyle>(self, style: T) -> Range<T> {
+        Range {
+            low: self.low.with_style(style),
+            high: self.high,
+            separator: self.separator,
+            word: self.word,
+        }
+    }
+
+    /// Set what goes between numbers sharing a unit, `"–"` by default.
+    pub fn with_separator(self, separator: &'static str) -> Self {
+        Self { separator, ..self }
+    }
+
+    /// Set the word between ends with their own units, `"to"` by default.
+    pub fn with_word(self, word: &'static str) -> Self {
+        Self { word, ..self }
+    }
+
+    pub fn low(&self) -> std::time::Duration {
+        self.low.0
+    }
+
+    pub fn high(&self) -> std::time::Duration {
+        self.high
+    }
+}
+
+/// `part` without trailing zeros in its fractional digits, e.g. `1.5h` for `1.50h`.
+fn trimmed(mut part: Part) -> Part {
+    while part.frac_digits > 0 && part.frac % 10 == 0 {
+        part.frac /= 10;
+        part.frac_digits -= 1;
+    }
+    part
+}
+
+fn trimmed_parts(parts: Parts) -> Parts {
+    match parts.second() {
+        Some(second) => Parts::two(trimmed(parts.first()), trimmed(second)),
+        None => Parts::one(trimmed(parts.first())),
+    }
+}
+
+/// Both ends in a unit they share, or [None] if the smaller end would be 0 in it.
+///
+/// `high` is the part the style shows for the larger end.
+fn shared_unit(
+    low: std::time::Duration,
+    high: std::time::Duration,
+    shown: Part,
+) -> Option<(Part, Part)> {
+    let exact = |part: Part, d| Parts::one(part).is_exact(d);
+    if !exact(shown, high) {
+        if let Some(unit) = shown.unit.smaller() {
+            let (lo, hi) = (Part::significant(low, unit), Part::significant(high, unit));
+            if hi.whole < 1000 && exact(lo, low) && exact(hi, high) {
+                return Some((lo, hi));
+            }
+        }
+    }
+    let lo = Part::in_unit(low, shown.unit, shown.frac_digits);
+    if lo.whole == 0 && lo.frac == 0 && !low.is_zero() {
+        return None;
+    }
+    Some((lo, shown))
+}
+
+impl<S: DurationStyle> Display for Range<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let style = &self.low.1;
+        let (low, high) = (self.low.0, self.high);
+        let parts = style.parts(high);
+        // Styles writing something else than their parts, e.g. words, are kept as they are.
+        if parts.to_string() != Duration(high, style).to_string() {
+            let (low, high) = (Duration(low, style), Duration(high, style));
+            return match low.to_string() == high.to_string() {
+                true => write!(f, "{high}"),
+                false => write!(f, "{low} {} {high}", self.word),
+            };
+        }
+        let shared = match parts.second() {
+            None => shared_unit(low, high, parts.first()),
+            Some(_) => None,
+        };
+        let Some((lo, hi)) = shared else {
+            let (low, high) = (trimmed_parts(style.parts(low)), trimmed_parts(parts));
+            return match low == high {
+                true => write!(f, "{high}"),
+                false => write!(f, "{low} {} {high}", self.word),
+            };
+        };
+        let (lo, hi) = (trimmed(lo), trimmed(hi));
+        if lo == hi {
+            return write!(f, "{hi}");
+        }
+        lo.fmt_value(f)?;
+        f.write_str(self.separator)?;
+        hi.fmt_value(f)?;
+        write!(f, "{}", hi.unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        duration::{Fuzzy, Phrase, Style},
+        Folktime,
+    };
+    use std::time::Duration;
+
+    fn range(a: Duration, b: Duration) -> String {
+        Folktime::range(a, b).to_string()
+    }
+
+    #[test]
+    fn shared_unit() {
+        let ms = Duration::from_millis;
+        assert_eq!(range(ms(12_300), ms(15_100)), "12.3–15.1s");
+        assert_eq!(range(ms(15_100), ms(12_300)), "12.3–15.1s");
+        assert_eq!(range(ms(100), ms(250)), "100–250ms");
+        assert_eq!(range(ms(1_000), ms(1_500)), "1–1.5s");
+        assert_eq!(range(Duration::ZERO, ms(3_000)), "0–3s");
+    }
+    #[test]
+    fn unit_of_larger_end() {
+        let secs = Duration::from_secs;
+        assert_eq!(range(secs(30), secs(90)), "0.5–1.5m");
+        assert_eq!(range(secs(45 * 60), secs(7_200)), "0.75–2h");
+        assert_eq!(range(secs(5 * 60), secs(3_600)), "0.08–1h");
+    }
+    #[test]
+    fn smaller_unit_when_exact() {
+        let mins = |m: u64| Duration::from_secs(m * 60);
+        assert_eq!(range(mins(50), mins(70)), "50–70m");
+        assert_eq!(range(mins(50), mins(90)), "0.83–1.5h");
+        let ms = Duration::from_millis;
+        assert_eq!(range(ms(12_345), ms(15_123)), "12.3–15.1s");
+    }
+    #[test]
+    fn own_units() {
+        let secs = Duration::from_secs;
+        assert_eq!(range(secs(300), secs(86_400 * 3)), "5m to 3d");
+        assert_eq!(range(Duration::from_micros(5), secs(2)), "5us to 2s");
+    }
+    #[test]
+    fn words() {
+        let secs = Duration::from_secs;
+        let r = Folktime::range(secs(30), secs(40)).with_style(Fuzzy::new());
+        assert_eq!(r.to_string(), "a few seconds");
+        let r = Folktime::range(secs(30), secs(50 * 60)).with_style(Fuzzy::new());
+        assert_eq!(r.to_string(), "a few seconds to an hour");
+        let r = Folktime::range(secs(134), secs(170)).with_style(Phrase);
+        assert_eq!(r.to_string(), "about 2 minutes to almost 3 minutes");
+    }
+    #[test]
+    fn same_ends() {
+        let ms = Duration::from_millis;
+        assert_eq!(range(ms(1_500), ms(1_500)), "1.5s");
+        assert_eq!(range(ms(1_500), ms(1_501)), "1.5s");
+    }
+    #[test]
+    fn options() {
+        let hours = |h: u64| Duration::from_secs(h * 3_600);
+        let r = Folktime::range(hours(2), hours(3)).with_separator(" to ");
+        assert_eq!(r.to_string(), "2 to 3h");
+        let r = Folktime::range(Duration::from_secs(5_400), hours(30))
+            .with_style(Style::TwoUnitsWhole)
+            .with_word("–");
+        assert_eq!(r.to_string(), "1h 30m – 1d 6h");
+        assert_eq!((r.low(), r.high()), (Duration::from_secs(5_400), hours(30)));
+        let r = Folktime::range(hours(2), hours(3)).with_style(Style::OneUnitWhole);
+        assert_eq!(r.to_string(), "2–3h");
+    }
+}
@@ -0,0 +1,191 @@
+use crate::duration::{fmt_quotient, Duration, DurationStyle, Style, Unit, NANOS_PER_SEC};
+use std::fmt::Display;
+
+/// How a [Rate] is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RateForm {
+    /// A frequency with an SI prefix, e.g. `60.0Hz` or `2.50kHz`.
+    #[default]
+    Frequency,
+    /// Events per second or per the smallest larger unit that has at least one,
+    /// e.g. `3.00/h` or `12.0/min`.
+    PerUnit,
+    /// The period itself, e.g. `every 20.0m`.
+    Every,
+}
+
+/// A rate given by its period, such as a polling interval or a tick rate.
+///
+/// Numbers have three significant digits and are truncated, like
+/// [Style::OneUnitFrac](crate::duration::Style::OneUnitFrac).
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::Folktime;
+/// use folktime::rate::RateForm;
+///
+/// let tick = Folktime::rate(Duration::from_micros(400));
+/// assert_eq!(tick.to_string(), "2.50kHz");
+///
+/// let poll = || Folktime::rate(Duration::from_secs(20 * 60));
+/// assert_eq!(poll().with_form(RateForm::PerUnit).to_string(), "3.00/h");
+/// assert_eq!(poll().with_form(RateForm::Every).to_string(), "every 20.0m");
+/// ```
+pub struct Rate<S = Style> {
+    period: Duration<S>,
+    form: RateForm,
+}
+
+impl Rate {
+    /// A rate of one event every `period`.
+    pub fn new(period: std::time::Duration) -> Self {
+        Self {
+            period: Duration::new(period),
+            form: RateForm::Frequency,
+        }
+    }
+}
+
+impl<S> Rate<S> {
+    /// Set the formatting style of the period, used by [RateForm::Every].
+    pub fn with_style<T: DurationStyle>(self, style: T) -> Rate<T> {
+        Rate {
+            period: self.period.with_style(style),
+            form: self.form,
+        }
+    }
+
+    pub fn with_form(self, form: RateForm) -> Self {
+        Self { form, ..self }
+    }
+
+    pub fn period(&self) -> std::time::Duration {
+        self.period.0
+    }
+
+    /// Events per second.
+    pub fn hz(&self) -> f64 {
+        1.0 / self.period.0.as_secs_f64()
+    }
+}
+
+/// SI prefixes and their powers of ten, largest first.
+const PREFIXES: [(&str, i32); 7] = [
+    ("G", 9),
+    ("M", 6),
+    ("k", 3),
+    ("", 0),
+    ("m", -3),
+    ("µ", -6),
+    ("n", -9),
+];
+
+fn fmt_frequency(period: u128, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    // Hz = 10^9 / period, and with a prefix of 10^e, 10^9 / (period * 10^e).
+    let fraction = |e: i32| {
+        let num = NANOS_PER_SEC * 10u128.pow((-e).max(0) as u32);
+        let den = period * 10u128.pow(e.max(0) as u32);
+        (num, den)
+    };
+    let (prefix, e) = PREFIXES
+        .into_iter()
+        .find(|(_, e)| {
+            let (num, den) = fraction(*e);
+            num >= den
+        })
+        .unwrap_or(PREFIXES[PREFIXES.len() - 1]);
+    let (num, den) = fraction(e);
+    fmt_quotient(num, den, f)?;
+    write!(f, "{prefix}Hz")
+}
+
+fn fmt_per_unit(period: u128, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let unit = Unit::LADDER
+        .into_iter()
+        .skip_while(|unit| *unit < Unit::Second)
+        .find(|unit| unit.as_nanos() >= period)
+        .unwrap_or(Unit::GigaYear);
+    fmt_quotient(unit.as_nanos(), period, f)?;
+    match unit {
+        // `m` could be read as metres.
+        Unit::Minute => write!(f, "/min"),
+        unit => write!(f, "/{unit}"),
+    }
+}
+
+impl<S: DurationStyle> Display for Rate<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let period = self.period.0.as_nanos();
+        match self.form {
+            RateForm::Every => write!(f, "every {}", self.period),
+            _ if period == 0 => write!(f, "∞"),
+            RateForm::Frequency => fmt_frequency(period, f),
+            RateForm::PerUnit => fmt_per_unit(period, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Folktime;
+    use std::time::Duration;
+
+    fn frequency(period: Duration) -> String {
+        Folktime::rate(period).to_string()
+    }
+
+    fn per_unit(period: Duration) -> String {
+        Folktime::rate(period)
+            .with_form(RateForm::PerUnit)
+            .to_string()
+    }
+
+    #[test]
+    fn frequencies() {
+        assert_eq!(frequency(Duration::from_nanos(16_666_667)), "59.9Hz");
+        assert_eq!(frequency(Duration::from_nanos(16_666_666)), "60.0Hz");
+        assert_eq!(frequency(Duration::from_micros(400)), "2.50kHz");
+        assert_eq!(frequency(Duration::from_nanos(1)), "1.00GHz");
+        assert_eq!(frequency(Duration::from_nanos(3)), "333MHz");
+        assert_eq!(frequency(Duration::from_secs(1)), "1.00Hz");
+        assert_eq!(frequency(Duration::from_secs(2)), "500mHz");
+        assert_eq!(frequency(Duration::from_secs(20 * 60)), "833µHz");
+        assert_eq!(frequency(Duration::from_secs(31_558_150)), "31.6nHz");
+        assert_eq!(
+            frequency(Duration::from_secs(31_558_150 * 1000)),
+            "0.031nHz"
+        );
+    }
+    #[test]
+    fn per_units() {
+        assert_eq!(per_unit(Duration::from_millis(20)), "50.0/s");
+        assert_eq!(per_unit(Duration::from_micros(400)), "2500/s");
+        assert_eq!(per_unit(Duration::from_secs(1)), "1.00/s");
+        assert_eq!(per_unit(Duration::from_secs(5)), "12.0/min");
+        assert_eq!(per_unit(Duration::from_secs(20 * 60)), "3.00/h");
+        assert_eq!(per_unit(Duration::from_secs(7 * 3_600)), "3.42/d");
+        assert_eq!(per_unit(Duration::from_secs(2 * 86_400)), "3.50/w");
+    }
+    #[test]
+    fn every() {
+        let rate = Folktime::rate(Duration::from_secs(300)).with_form(RateForm::Every);
+        assert_eq!(rate.to_string(), "every 5.00m");
+        let rate = rate.with_style(crate::duration::Style::OneUnitWhole);
+        assert_eq!(rate.to_string(), "every 5m");
+    }
+    #[test]
+    fn zero() {
+        assert_eq!(frequency(Duration::ZERO), "∞");
+        assert_eq!(per_unit(Duration::ZERO), "∞");
+    }
+    #[test]
+    fn hz() {
+        assert_eq!(Folktime::rate(Duration::from_millis(250)).hz(), 4.0);
+        assert_eq!(
+            Folktime::rate(Duration::from_millis(250)).period(),
+            Duration::from_millis(250)
+        );
+    }
+}
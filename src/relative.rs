@@ -0,0 +1,126 @@
+use crate::duration::{Duration, DurationStyle, Style};
+use std::fmt::Display;
+use std::time::SystemTime;
+
+/// A point in time relative to a reference time, formatted as e.g. `3.20m ago` or `in 5.00s`.
+///
+/// # Example
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use folktime::Folktime;
+///
+/// let now = SystemTime::now();
+/// let a = Folktime::relative(now - Duration::from_secs(192), now);
+/// let b = Folktime::relative(now + Duration::from_secs(5), now);
+///
+/// assert_eq!(format!("{}", a), "3.20m ago");
+/// assert_eq!(format!("{}", b), "in 5.00s");
+/// ```
+pub struct Relative<S = Style> {
+    offset: Duration<S>,
+    future: bool,
+}
+
+impl Relative {
+    /// `time` relative to `now`.
+    pub fn new(time: SystemTime, now: SystemTime) -> Self {
+        match now.duration_since(time) {
+            Ok(d) => Self {
+                offset: Duration::new(d),
+                future: false,
+            },
+            Err(e) => Self {
+                offset: Duration::new(e.duration()),
+                future: true,
+            },
+        }
+    }
+}
+
+impl<S> Relative<S> {
+    /// Set the formatting style of the offset.
+    pub fn with_style<T: DurationStyle>(self, style: T) -> Relative<T> {
+        Relative {
+            offset: self.offset.with_style(style),
+            future: self.future,
+        }
+    }
+
+    /// Distance between the time and the reference time.
+    pub fn offset(&self) -> std::time::Duration {
+        self.offset.0
+    }
+
+    /// Whether the time is after the reference time.
+    pub fn is_future(&self) -> bool {
+        self.future
+    }
+}
+
+impl<S: DurationStyle> Relative<S> {
+    /// How long until the formatted output changes, as time passes, e.g. to schedule the
+    /// next repaint. See [Duration::next_change].
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use folktime::Folktime;
+    /// use folktime::duration::Style;
+    ///
+    /// let now = SystemTime::now();
+    /// let d = Folktime::relative(now - Duration::from_secs(200), now)
+    ///     .with_style(Style::OneUnitWhole);
+    /// assert_eq!(d.to_string(), "3m ago");
+    /// assert_eq!(d.next_change(), Some(Duration::from_secs(40)));
+    /// ```
+    pub fn next_change(&self) -> Option<std::time::Duration> {
+        if self.future {
+            self.offset.next_change_shrinking()
+        } else {
+            self.offset.next_change()
+        }
+    }
+}
+
+impl<S: DurationStyle> Display for Relative<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.future {
+            write!(f, "in {}", self.offset)
+        } else {
+            write!(f, "{} ago", self.offset)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{duration::Style, Folktime};
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn past() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let d = Folktime::relative(now - Duration::from_millis(12_300), now);
+        assert_eq!(format!("{}", d), "12.3s ago");
+        assert!(!d.is_future());
+        assert_eq!(d.offset(), Duration::from_millis(12_300));
+        assert_eq!(d.next_change(), Some(Duration::from_millis(100)));
+    }
+    #[test]
+    fn future() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let d = Folktime::relative(now + Duration::from_secs(3 * 3600 + 60), now)
+            .with_style(Style::TwoUnitsWhole);
+        assert_eq!(format!("{}", d), "in 3h 1m");
+        assert!(d.is_future());
+        assert_eq!(d.next_change(), Some(Duration::from_nanos(1)));
+        let d = Folktime::relative(now + Duration::from_millis(5_004), now);
+        assert_eq!(d.next_change(), Some(Duration::from_nanos(4_000_001)));
+    }
+    #[test]
+    fn now() {
+        let now = SystemTime::UNIX_EPOCH;
+        let d = Folktime::relative(now, now);
+        assert_eq!(format!("{}", d), "0.00s ago");
+    }
+}
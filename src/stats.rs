@@ -0,0 +1,247 @@
+use crate::duration::{from_nanos, Part, Unit, NANOS_PER_SEC};
+use std::fmt::Display;
+use std::time::Duration;
+
+/// Collects duration samples, e.g. from benchmark iterations or request latencies.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::stats::Samples;
+///
+/// let samples: Samples = (1..=100).map(Duration::from_micros).collect();
+/// let summary = samples.summary().unwrap();
+///
+/// assert_eq!(summary.median, Duration::from_micros(50));
+/// assert_eq!(
+///     summary.to_string(),
+///     "n=100 min 1.00us p50 50.0us p95 95.0us p99 99.0us max 100us mean 50.5us ± 29.0us"
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Samples {
+    samples: Vec<Duration>,
+}
+
+impl Samples {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, sample: Duration) {
+        self.samples.push(sample);
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Statistics of the samples, or `None` if there are none.
+    pub fn summary(&self) -> Option<Summary> {
+        let mut sorted = self.samples.clone();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        if n == 0 {
+            return None;
+        }
+        let percentile = |p: u128| sorted[((n as u128 * p).div_ceil(100) as usize).max(1) - 1];
+        let total: u128 = sorted.iter().map(Duration::as_nanos).sum();
+        let mean = total / n as u128;
+        let variance = if n > 1 {
+            let squares: f64 = sorted
+                .iter()
+                .map(|d| (d.as_nanos() as f64 - mean as f64).powi(2))
+                .sum();
+            squares / (n - 1) as f64
+        } else {
+            0.0
+        };
+        let std_dev = variance.sqrt() / NANOS_PER_SEC as f64;
+        let std_dev = Duration::try_from_secs_f64(std_dev).unwrap_or(Duration::MAX);
+        let mean = from_nanos(mean);
+        // The smallest value shown keeps a significant digit in the three fractional digits.
+        let smallest = [sorted[0], std_dev]
+            .into_iter()
+            .filter(|d| !d.is_zero())
+            .min();
+        let mut unit = Unit::largest_in(mean);
+        while let (Some(smallest), Some(smaller)) = (smallest, unit.smaller()) {
+            if smallest.as_nanos() * 1_000 >= unit.as_nanos() {
+                break;
+            }
+            unit = smaller;
+        }
+        Some(Summary {
+            count: n,
+            min: sorted[0],
+            max: sorted[n - 1],
+            mean,
+            median: percentile(50),
+            p95: percentile(95),
+            p99: percentile(99),
+            std_dev,
+            unit,
+        })
+    }
+}
+
+impl Extend<Duration> for Samples {
+    fn extend<T: IntoIterator<Item = Duration>>(&mut self, iter: T) {
+        self.samples.extend(iter)
+    }
+}
+
+impl FromIterator<Duration> for Samples {
+    fn from_iter<T: IntoIterator<Item = Duration>>(iter: T) -> Self {
+        Self {
+            samples: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Statistics of [Samples], formatted on one line with all values in the same unit,
+/// e.g. `n=1000 min 1.02ms p50 1.31ms p95 2.10ms p99 4.80ms max 12.1ms mean 1.35ms ± 0.12ms`.
+///
+/// The unit is the one of the mean, so that large outliers don't get many digits, or a
+/// smaller one in which the minimum and the standard deviation still show a significant
+/// digit, unless set with [Summary::with_unit].
+/// Percentiles are nearest-rank, and the standard deviation is of a sample.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Summary {
+    pub count: usize,
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    pub std_dev: Duration,
+    unit: Unit,
+}
+
+impl Summary {
+    /// Show all values in `unit`.
+    pub fn with_unit(self, unit: Unit) -> Self {
+        Self { unit, ..self }
+    }
+
+    /// `d` in the unit of the summary.
+    pub fn part(&self, d: Duration) -> Part {
+        Part::significant(d, self.unit)
+    }
+
+    /// The mean and the standard deviation, e.g. `1.31ms ± 0.12ms`.
+    pub fn mean_std_dev(&self) -> impl Display {
+        let mean = self.part(self.mean);
+        let std_dev = self.part(self.std_dev);
+        MeanStdDev(mean, std_dev)
+    }
+}
+
+struct MeanStdDev(Part, Part);
+
+impl Display for MeanStdDev {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} ± {}", self.0, self.1)
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "n={} min {} p50 {} p95 {} p99 {} max {} mean {}",
+            self.count,
+            self.part(self.min),
+            self.part(self.median),
+            self.part(self.p95),
+            self.part(self.p99),
+            self.part(self.max),
+            self.mean_std_dev(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Samples::new().summary(), None);
+    }
+    #[test]
+    fn single() {
+        let samples: Samples = [ms(5)].into_iter().collect();
+        let summary = samples.summary().unwrap();
+        assert_eq!(
+            (summary.min, summary.median, summary.max),
+            (ms(5), ms(5), ms(5))
+        );
+        assert_eq!(summary.std_dev, Duration::ZERO);
+        assert_eq!(
+            summary.to_string(),
+            "n=1 min 5.00ms p50 5.00ms p95 5.00ms p99 5.00ms max 5.00ms mean 5.00ms ± 0.000ms"
+        );
+    }
+    #[test]
+    fn statistics() {
+        let mut samples = Samples::new();
+        samples.extend([ms(3), ms(1), ms(2), ms(4)]);
+        samples.push(ms(90));
+        assert_eq!(samples.len(), 5);
+        let summary = samples.summary().unwrap();
+        assert_eq!(summary.count, 5);
+        assert_eq!(summary.min, ms(1));
+        assert_eq!(summary.median, ms(3));
+        assert_eq!(summary.p95, ms(90));
+        assert_eq!(summary.max, ms(90));
+        assert_eq!(summary.mean, ms(20));
+        assert_eq!(summary.std_dev.as_millis(), 39);
+    }
+    #[test]
+    fn common_unit() {
+        let samples: Samples = [
+            Duration::from_micros(512),
+            ms(1),
+            Duration::from_micros(1_310),
+            ms(1_200),
+        ]
+        .into_iter()
+        .collect();
+        let summary = samples.summary().unwrap();
+        assert_eq!(
+            summary.to_string(),
+            "n=4 min 0.512ms p50 1.00ms p95 1200ms p99 1200ms max 1200ms mean 300ms ± 599ms"
+        );
+        assert_eq!(
+            summary.with_unit(Unit::Second).mean_std_dev().to_string(),
+            "0.300s ± 0.599s"
+        );
+    }
+    #[test]
+    fn outliers() {
+        let samples: Samples = [Duration::from_micros(999), ms(2), ms(5_000)]
+            .into_iter()
+            .collect();
+        let summary = samples.summary().unwrap();
+        assert_eq!(
+            summary.to_string(),
+            "n=3 min 0.999ms p50 2.00ms p95 5000ms p99 5000ms max 5000ms mean 1667ms ± 2885ms"
+        );
+    }
+    #[test]
+    fn large() {
+        let samples: Samples = [Duration::ZERO, Duration::MAX].into_iter().collect();
+        let summary = samples.summary().unwrap();
+        assert!(summary.std_dev > Duration::from_secs(u64::MAX / 2));
+    }
+}
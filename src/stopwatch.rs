@@ -0,0 +1,294 @@
+use crate::clock::{Clock, SystemClock};
+use crate::duration::{Duration, DurationStyle, Style};
+use std::fmt::Display;
+use std::time::Instant;
+
+/// Measures elapsed time that can be paused, and records laps.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use folktime::clock::ManualClock;
+/// use folktime::stopwatch::Stopwatch;
+///
+/// let clock = ManualClock::new();
+/// let mut watch = Stopwatch::with_clock(&clock);
+/// watch.start();
+/// clock.advance(Duration::from_millis(1_500));
+/// watch.lap();
+/// clock.advance(Duration::from_millis(3_500));
+/// watch.lap();
+///
+/// assert_eq!(
+///     watch.report().to_string(),
+///     "\
+/// lap 1   1.50s   1.50s   30.0%
+/// lap 2   3.50s   5.00s   70.0%
+/// total           5.00s
+/// "
+/// );
+/// ```
+pub struct Stopwatch<C = SystemClock> {
+    clock: C,
+    /// Time measured before the last start or resume.
+    stored: std::time::Duration,
+    running_since: Option<Instant>,
+    /// Total time at each lap.
+    laps: Vec<std::time::Duration>,
+}
+
+impl Stopwatch {
+    /// A stopped stopwatch using the real clock.
+    pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+
+    /// A stopwatch that has been started.
+    pub fn start_new() -> Self {
+        let mut watch = Self::new();
+        watch.start();
+        watch
+    }
+}
+
+impl Default for Stopwatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Clock> Stopwatch<C> {
+    /// A stopped stopwatch using `clock`.
+    pub fn with_clock(clock: C) -> Self {
+        Self {
+            clock,
+            stored: std::time::Duration::ZERO,
+            running_since: None,
+            laps: vec![],
+        }
+    }
+
+    /// Clear the elapsed time and the laps, and start measuring.
+    pub fn start(&mut self) {
+        self.reset();
+        self.running_since = Some(self.clock.now());
+    }
+
+    /// Pause measuring. Does nothing if stopped.
+    pub fn stop(&mut self) {
+        self.stored = self.elapsed();
+        self.running_since = None;
+    }
+
+    /// Continue measuring after [Stopwatch::stop]. Does nothing if running.
+    pub fn resume(&mut self) {
+        if self.running_since.is_none() {
+            self.running_since = Some(self.clock.now());
+        }
+    }
+
+    /// Clear the elapsed time and the laps, and stop.
+    pub fn reset(&mut self) {
+        self.stored = std::time::Duration::ZERO;
+        self.running_since = None;
+        self.laps.clear();
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    /// Measured time, not counting the time while stopped.
+    pub fn elapsed(&self) -> std::time::Duration {
+        let running = self
+            .running_since
+            .map_or(std::time::Duration::ZERO, |since| {
+                self.clock.now().saturating_duration_since(since)
+            });
+        self.stored + running
+    }
+
+    /// End the current lap and return its duration.
+    pub fn lap(&mut self) -> std::time::Duration {
+        let total = self.elapsed();
+        let previous = self.laps.last().copied().unwrap_or_default();
+        self.laps.push(total);
+        total.saturating_sub(previous)
+    }
+
+    /// The recorded laps.
+    pub fn laps(&self) -> Vec<Lap> {
+        let mut previous = std::time::Duration::ZERO;
+        self.laps
+            .iter()
+            .map(|&total| {
+                let split = total - previous;
+                previous = total;
+                Lap { split, total }
+            })
+            .collect()
+    }
+
+    /// The laps and the elapsed time, to be displayed.
+    pub fn report(&self) -> Report {
+        Report {
+            laps: self.laps(),
+            total: Duration::new(self.elapsed()),
+        }
+    }
+}
+
+/// A lap of a [Stopwatch].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lap {
+    /// Duration of the lap.
+    pub split: std::time::Duration,
+    /// Time from the start to the end of the lap.
+    pub total: std::time::Duration,
+}
+
+/// Laps of a [Stopwatch], with their durations, totals and shares of the elapsed time,
+/// one per line.
+pub struct Report<S = Style> {
+    laps: Vec<Lap>,
+    total: Duration<S>,
+}
+
+impl<S> Report<S> {
+    /// Set the formatting style of the times.
+    pub fn with_style<T: DurationStyle>(self, style: T) -> Report<T> {
+        Report {
+            laps: self.laps,
+            total: self.total.with_style(style),
+        }
+    }
+
+    pub fn laps(&self) -> &[Lap] {
+        &self.laps
+    }
+
+    pub fn total(&self) -> std::time::Duration {
+        self.total.0
+    }
+
+    /// Percentage of the elapsed time taken by `lap`.
+    pub fn share(&self, lap: &Lap) -> f64 {
+        if self.total.0.is_zero() {
+            0.0
+        } else {
+            lap.split.as_secs_f64() / self.total.0.as_secs_f64() * 100.0
+        }
+    }
+}
+
+impl<S: DurationStyle> Display for Report<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let style = &self.total.1;
+        let number_width = self.laps.len().to_string().len();
+        for (i, lap) in self.laps.iter().enumerate() {
+            writeln!(
+                f,
+                "lap {:<number_width$} {:>7} {:>7} {:>6.1}%",
+                i + 1,
+                Duration(lap.split, style),
+                Duration(lap.total, style),
+                self.share(lap),
+            )?;
+        }
+        // The elapsed time goes below the lap totals.
+        let label_width = "lap ".len() + number_width;
+        writeln!(f, "{:<label_width$} {:7} {:>7}", "total", "", self.total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use std::time::Duration;
+
+    #[test]
+    fn stop_and_resume() {
+        let clock = ManualClock::new();
+        let mut watch = Stopwatch::with_clock(&clock);
+        assert!(!watch.is_running());
+        watch.start();
+        clock.advance(Duration::from_secs(2));
+        watch.stop();
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(watch.elapsed(), Duration::from_secs(2));
+        watch.stop();
+        assert_eq!(watch.elapsed(), Duration::from_secs(2));
+        watch.resume();
+        watch.resume();
+        clock.advance(Duration::from_secs(3));
+        assert_eq!(watch.elapsed(), Duration::from_secs(5));
+        watch.start();
+        assert_eq!(watch.elapsed(), Duration::ZERO);
+        assert!(watch.is_running());
+    }
+    #[test]
+    fn laps() {
+        let clock = ManualClock::new();
+        let mut watch = Stopwatch::with_clock(&clock);
+        watch.start();
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(watch.lap(), Duration::from_secs(1));
+        clock.advance(Duration::from_secs(2));
+        watch.stop();
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(watch.lap(), Duration::from_secs(2));
+        assert_eq!(
+            watch.laps(),
+            [
+                Lap {
+                    split: Duration::from_secs(1),
+                    total: Duration::from_secs(1)
+                },
+                Lap {
+                    split: Duration::from_secs(2),
+                    total: Duration::from_secs(3)
+                }
+            ]
+        );
+        watch.reset();
+        assert!(watch.laps().is_empty());
+    }
+    #[test]
+    fn report() {
+        let clock = ManualClock::new();
+        let mut watch = Stopwatch::with_clock(&clock);
+        watch.start();
+        for secs in [50, 5, 3_600, 1, 1, 1, 1, 1, 1, 1] {
+            clock.advance(Duration::from_secs(secs));
+            watch.lap();
+        }
+        clock.advance(Duration::from_secs(38));
+        let report = watch
+            .report()
+            .with_style(crate::duration::Style::OneUnitWhole);
+        assert_eq!(report.laps().len(), 10);
+        assert_eq!(report.total(), Duration::from_secs(3_700));
+        assert_eq!(
+            report.to_string(),
+            "\
+lap 1      50s     50s    1.4%
+lap 2       5s     55s    0.1%
+lap 3       1h      1h   97.3%
+lap 4       1s      1h    0.0%
+lap 5       1s      1h    0.0%
+lap 6       1s      1h    0.0%
+lap 7       1s      1h    0.0%
+lap 8       1s      1h    0.0%
+lap 9       1s      1h    0.0%
+lap 10      1s      1h    0.0%
+total               1h
+"
+        );
+    }
+    #[test]
+    fn empty_report() {
+        let watch = Stopwatch::new();
+        assert_eq!(watch.report().to_string(), "total           0.00s\n");
+    }
+}
@@ -0,0 +1,183 @@
+use crate::duration::{pad, Duration, DurationStyle, Style, NANOS_PER_SEC};
+use std::fmt::Display;
+
+const ATTOS_PER_SEC: u128 = 1_000_000_000_000_000_000;
+
+/// A count of ticks or cycles at a clock rate, e.g. RTC ticks at 32768Hz or CPU cycles
+/// at 3.2GHz.
+///
+/// The conversion is exact, with 128-bit intermediate math, and the result is formatted
+/// with the [Style]. Below a nanosecond, the style is applied to the value scaled by 10^9,
+/// so it is shown in picoseconds, femtoseconds or attoseconds.
+///
+/// # Example
+/// ```
+/// use folktime::Folktime;
+/// use folktime::duration::Style;
+///
+/// assert_eq!(Folktime::ticks(49_152, 32_768).to_string(), "1.50s");
+/// assert_eq!(Folktime::ticks(1, 32_768).to_string(), "30.5us");
+/// assert_eq!(Folktime::ticks(1, 3_200_000_000).to_string(), "312ps");
+///
+/// let t = Folktime::ticks(1, 3_200_000_000).with_style(Style::TwoUnitsWhole);
+/// assert_eq!(t.to_string(), "312ps 500fs");
+/// ```
+pub struct Ticks<S = Style> {
+    duration: Duration<S>,
+    count: u64,
+    hz: u64,
+}
+
+impl Ticks {
+    /// `count` ticks of a clock running at `hz` ticks per second.
+    ///
+    /// # Panics
+    /// If `hz` is zero.
+    pub fn new(count: u64, hz: u64) -> Self {
+        assert!(hz > 0, "tick rate must not be zero");
+        let secs = count / hz;
+        let nanos = (count % hz) as u128 * NANOS_PER_SEC / hz as u128;
+        Self {
+            duration: Duration::new(std::time::Duration::new(secs, nanos as u32)),
+            count,
+            hz,
+        }
+    }
+}
+
+impl<S> Ticks<S> {
+    /// Set the formatting style.
+    pub fn with_style<T: DurationStyle>(self, style: T) -> Ticks<T> {
+        Ticks {
+            duration: self.duration.with_style(style),
+            count: self.count,
+            hz: self.hz,
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn hz(&self) -> u64 {
+        self.hz
+    }
+
+    /// The time the ticks take, truncated to nanoseconds.
+    pub fn duration(&self) -> std::time::Duration {
+        self.duration.0
+    }
+
+    /// The time the ticks take in attoseconds, truncated, if it is under a nanosecond and
+    /// not below an attosecond.
+    fn sub_nanos(&self) -> Option<u64> {
+        if self.count == 0 || !self.duration.0.is_zero() {
+            return None;
+        }
+        // Under a nanosecond, `count < hz / 10^9`, so this can't overflow.
+        match (self.count as u128 * ATTOS_PER_SEC / self.hz as u128) as u64 {
+            0 => None,
+            attos => Some(attos),
+        }
+    }
+}
+
+/// Formatting honors width, alignment and fill like [Duration].
+impl<S: DurationStyle> Display for Ticks<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let Some(attos) = self.sub_nanos() else {
+            return self.duration.fmt(f);
+        };
+        if f.width().is_some() {
+            pad(&SubNanos(attos, &self.duration.1).to_string(), f)
+        } else {
+            self.duration.1.fmt_sub_nanos(attos, f)
+        }
+    }
+}
+
+/// Formats attoseconds with [DurationStyle::fmt_sub_nanos], so they can be padded as a whole.
+struct SubNanos<'a, S>(u64, &'a S);
+
+impl<S: DurationStyle> Display for SubNanos<'_, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.1.fmt_sub_nanos(self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{duration::Style, Folktime};
+    use std::time::Duration;
+
+    fn ticks(count: u64, hz: u64) -> String {
+        Folktime::ticks(count, hz).to_string()
+    }
+
+    #[test]
+    fn rtc() {
+        assert_eq!(ticks(32_768, 32_768), "1.00s");
+        assert_eq!(ticks(1, 32_768), "30.5us");
+        assert_eq!(
+            Folktime::ticks(1, 32_768).duration(),
+            Duration::from_nanos(30_517)
+        );
+        assert_eq!(ticks(32_768 * 3_600, 32_768), "1.00h");
+    }
+    #[test]
+    fn game_ticks() {
+        assert_eq!(ticks(1, 20), "50.0ms");
+        assert_eq!(ticks(1_234, 20), "1.02m");
+        let t = Folktime::ticks(1_234, 20).with_style(Style::TwoUnitsWhole);
+        assert_eq!(t.to_string(), "1m 1s");
+        assert_eq!((t.count(), t.hz()), (1_234, 20));
+    }
+    #[test]
+    fn cycles() {
+        let ghz = 3_200_000_000;
+        assert_eq!(ticks(1, ghz), "312ps");
+        assert_eq!(ticks(3, ghz), "937ps");
+        assert_eq!(ticks(4, ghz), "1ns");
+        assert_eq!(ticks(ghz, ghz), "1.00s");
+        assert_eq!(ticks(u64::MAX, ghz), "182y");
+    }
+    #[test]
+    fn sub_nanosecond() {
+        assert_eq!(ticks(1, 32_000_000_000), "31.2ps");
+        assert_eq!(ticks(1, 1_000_000_000_000_000), "1.00fs");
+        assert_eq!(ticks(1, 1_000_000_000_000_000_000), "1as");
+        assert_eq!(ticks(1, u64::MAX), "0.00s");
+        let t = Folktime::ticks(1, 3_200_000_000).with_style(Style::OneUnitWhole);
+        assert_eq!(t.to_string(), "312ps");
+    }
+    #[test]
+    fn sub_nanosecond_styles() {
+        use crate::duration::{Approx, Fraction, Fuzzy, Phrase};
+        let t = || Folktime::ticks(1, 3_200_000_000);
+        assert_eq!(t().with_style(Fuzzy::new()).to_string(), "a few seconds");
+        assert_eq!(t().with_style(Phrase).to_string(), "over 312 picoseconds");
+        let approx = Approx::new(Style::OneUnitFrac);
+        assert_eq!(t().with_style(approx).to_string(), "~312ps");
+        let t = || Folktime::ticks(1, 1_000_000_000_000);
+        assert_eq!(t().with_style(approx).to_string(), "1.00ps");
+        assert_eq!(t().with_style(Phrase).to_string(), "a picosecond");
+        assert_eq!(t().with_style(Fraction::words()).to_string(), "1.00ps");
+    }
+    #[test]
+    fn width() {
+        assert_eq!(
+            format!("[{:>8}]", Folktime::ticks(1, 3_200_000_000)),
+            "[   312ps]"
+        );
+        assert_eq!(format!("[{:<7}]", Folktime::ticks(1, 20)), "[50.0ms ]");
+    }
+    #[test]
+    fn zero() {
+        assert_eq!(ticks(0, 32_768), "0.00s");
+    }
+    #[test]
+    #[should_panic]
+    fn zero_rate() {
+        Folktime::ticks(1, 0);
+    }
+}
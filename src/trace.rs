@@ -0,0 +1,244 @@
+//! Span timings and duration fields for [tracing], formatted in a human-friendly way.
+//!
+//! Requires the `tracing` feature.
+
+use crate::duration::{Duration, DurationStyle, Style};
+use std::io::Write;
+use std::time::Instant;
+use tracing::field::{display, DisplayValue};
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// A [Layer] that prints how long every span was busy and idle when it closes.
+///
+/// Time spent inside the span is busy, time between its creation, its entries and its
+/// closing is idle. Lines are written to standard error by default, e.g.
+/// `request: busy 12.3ms, idle 1.02s, total 1.03s`.
+///
+/// # Example
+/// ```
+/// use folktime::duration::Style;
+/// use folktime::trace::SpanTimes;
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// let subscriber = tracing_subscriber::registry()
+///     .with(SpanTimes::new().with_style(Style::TwoUnitsWhole));
+/// tracing::subscriber::with_default(subscriber, || {
+///     let _span = tracing::info_span!("request").entered();
+/// });
+/// ```
+pub struct SpanTimes<S = Style, W = fn() -> std::io::Stderr> {
+    style: S,
+    make_writer: W,
+}
+
+impl SpanTimes {
+    pub fn new() -> Self {
+        Self {
+            style: Style::OneUnitFrac,
+            make_writer: std::io::stderr,
+        }
+    }
+}
+
+impl Default for SpanTimes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, W> SpanTimes<S, W> {
+    /// Set the formatting style of the times.
+    pub fn with_style<T: DurationStyle>(self, style: T) -> SpanTimes<T, W> {
+        SpanTimes {
+            style,
+            make_writer: self.make_writer,
+        }
+    }
+
+    /// Write the lines somewhere else than standard error.
+    pub fn with_writer<M: for<'a> MakeWriter<'a>>(self, make_writer: M) -> SpanTimes<S, M> {
+        SpanTimes {
+            style: self.style,
+            make_writer,
+        }
+    }
+}
+
+/// Busy and idle time of a span, kept in its extensions.
+struct Timings {
+    busy: std::time::Duration,
+    idle: std::time::Duration,
+    last: Instant,
+}
+
+impl Timings {
+    fn update(&mut self, busy: bool) {
+        let now = Instant::now();
+        let elapsed = now - self.last;
+        if busy {
+            self.busy += elapsed;
+        } else {
+            self.idle += elapsed;
+        }
+        self.last = now;
+    }
+}
+
+impl<Sub, S, W> Layer<Sub> for SpanTimes<S, W>
+where
+    Sub: Subscriber + for<'a> LookupSpan<'a>,
+    S: DurationStyle + Send + Sync + 'static,
+    W: for<'a> MakeWriter<'a> + 'static,
+{
+    fn on_new_span(&self, _attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, Sub>) {
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(Timings {
+                busy: std::time::Duration::ZERO,
+                idle: std::time::Duration::ZERO,
+                last: Instant::now(),
+            });
+        }
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, Sub>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(timings) = span.extensions_mut().get_mut::<Timings>() {
+                timings.update(false);
+            }
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, Sub>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(timings) = span.extensions_mut().get_mut::<Timings>() {
+                timings.update(true);
+            }
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, Sub>) {
+        let Some(span) = ctx.span(&id) else { return };
+        let mut extensions = span.extensions_mut();
+        let Some(timings) = extensions.get_mut::<Timings>() else {
+            return;
+        };
+        timings.update(false);
+        let duration = |d| Duration(d, &self.style);
+        // Like the fmt layer, a failed write is ignored rather than panicking.
+        let _ = writeln!(
+            self.make_writer.make_writer(),
+            "{}: busy {}, idle {}, total {}",
+            span.name(),
+            duration(timings.busy),
+            duration(timings.idle),
+            duration(timings.busy + timings.idle),
+        );
+    }
+}
+
+/// A [std::time::Duration] to record as a field, formatted in the default style.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+///
+/// let d = Duration::from_millis(12_345);
+/// tracing::info!(elapsed = folktime::trace::duration(d), "done");
+/// assert_eq!(format!("{:?}", folktime::trace::duration(d)), "12.3s");
+/// ```
+pub fn duration(d: std::time::Duration) -> DisplayValue<Duration> {
+    display(Duration::new(d))
+}
+
+/// A [std::time::Duration] to record as a field, formatted in the given style.
+pub fn duration_with_style<S: DurationStyle>(
+    d: std::time::Duration,
+    style: S,
+) -> DisplayValue<Duration<S>> {
+    display(Duration(d, style))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::duration::parse;
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::layer::SubscriberExt;
+
+    /// Collects everything written by the layer.
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Buffer {
+        type Writer = Buffer;
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn lines(f: impl FnOnce()) -> Vec<String> {
+        let buffer = Buffer::default();
+        let subscriber =
+            tracing_subscriber::registry().with(SpanTimes::new().with_writer(buffer.clone()));
+        tracing::subscriber::with_default(subscriber, f);
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        output.lines().map(str::to_string).collect()
+    }
+
+    /// The busy, idle and total times of a line.
+    fn times(line: &str) -> [std::time::Duration; 3] {
+        let (_, times) = line.split_once(": ").unwrap();
+        let times: Vec<_> = times
+            .split(", ")
+            .map(|t| parse(t.split_once(' ').unwrap().1).unwrap())
+            .collect();
+        [times[0], times[1], times[2]]
+    }
+
+    #[test]
+    fn busy_and_idle() {
+        let lines = lines(|| {
+            let span = tracing::info_span!("work");
+            span.in_scope(|| std::thread::sleep(std::time::Duration::from_millis(20)));
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        });
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].starts_with("work: busy "), "{}", lines[0]);
+        let [busy, idle, total] = times(&lines[0]);
+        assert!(busy >= std::time::Duration::from_millis(20));
+        assert!(idle >= std::time::Duration::from_millis(10));
+        assert!(total >= busy + idle - std::time::Duration::from_millis(1));
+    }
+    #[test]
+    fn nested() {
+        let lines = lines(|| {
+            let _outer = tracing::info_span!("outer").entered();
+            let _inner = tracing::info_span!("inner").entered();
+        });
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("inner: "));
+        assert!(lines[1].starts_with("outer: "));
+    }
+    #[test]
+    fn fields() {
+        let d = std::time::Duration::from_secs(3723);
+        assert_eq!(format!("{:?}", duration(d)), "1.03h");
+        assert_eq!(
+            format!("{:?}", duration_with_style(d, Style::TwoUnitsWhole)),
+            "1h 2m"
+        );
+    }
+}